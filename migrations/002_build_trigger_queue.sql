@@ -0,0 +1,9 @@
+-- =============================================================================
+-- 빌드 트리거 Jenkins 큐 추적
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- buildWithParameters 응답의 Location (Jenkins 큐 아이템 URL)
+-- 큐 대기 중인 빌드의 실제 빌드 번호를 나중에 확인하기 위해 저장
+-- -----------------------------------------------------------------------------
+ALTER TABLE build_triggers ADD COLUMN IF NOT EXISTS jenkins_queue_url VARCHAR(512);
@@ -0,0 +1,13 @@
+-- =============================================================================
+-- 빌드 완료 추적
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- Jenkins 빌드 소요 시간 (밀리초, 빌드 완료 시 기록)
+-- -----------------------------------------------------------------------------
+ALTER TABLE build_triggers ADD COLUMN IF NOT EXISTS build_duration_ms BIGINT;
+
+-- 진행 중인 빌드 조회용 인덱스
+CREATE INDEX IF NOT EXISTS idx_build_triggers_in_flight
+    ON build_triggers (trigger_status)
+    WHERE completed_at IS NULL;
@@ -0,0 +1,10 @@
+-- =============================================================================
+-- GitHub 커밋 상태 보고
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- 커밋 상태(commit status)의 context 이름
+-- PR 페이지의 체크 목록에 표시되는 이름으로, 매핑마다 다르게 지정 가능
+-- -----------------------------------------------------------------------------
+ALTER TABLE jenkins_mappings
+    ADD COLUMN IF NOT EXISTS status_context VARCHAR(255) NOT NULL DEFAULT 'pr-bridge/jenkins';
@@ -0,0 +1,15 @@
+-- =============================================================================
+-- 수동 재빌드 (force)
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- 같은 PR/커밋의 빌드 시도 번호
+-- 자동 트리거는 항상 1번 시도만 기록하여 커밋당 한 번만 트리거되고,
+-- 수동 강제 재빌드는 시도 번호를 증가시켜 새 이력으로 기록
+-- -----------------------------------------------------------------------------
+ALTER TABLE build_triggers ADD COLUMN IF NOT EXISTS attempt INTEGER NOT NULL DEFAULT 1;
+
+ALTER TABLE build_triggers DROP CONSTRAINT IF EXISTS build_triggers_pull_request_id_commit_sha_key;
+ALTER TABLE build_triggers
+    ADD CONSTRAINT build_triggers_pull_request_id_commit_sha_attempt_key
+    UNIQUE (pull_request_id, commit_sha, attempt);
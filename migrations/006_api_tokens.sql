@@ -0,0 +1,17 @@
+-- =============================================================================
+-- WebSocket 관리 API 인증
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- API 토큰 테이블
+-- 토큰 원문은 저장하지 않고 SHA-256 해시(hex)만 보관
+-- 폐기된 토큰은 삭제하지 않고 revoked_at 기록 (이력 보존)
+-- -----------------------------------------------------------------------------
+CREATE TABLE IF NOT EXISTS api_tokens (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    token_hash VARCHAR(64) NOT NULL UNIQUE,
+    last_used_at TIMESTAMP,
+    revoked_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,15 @@
+-- =============================================================================
+-- API 토큰 역할 (권한)
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- 토큰별 역할
+-- viewer: 조회 / operator: 저장소·Jenkins 매핑 관리, 빌드 트리거
+-- admin: 시스템 설정 변경, 저장소 삭제, API 토큰 관리
+-- 기존 토큰은 이미 모든 명령을 실행할 수 있었으므로 admin으로 유지
+-- -----------------------------------------------------------------------------
+ALTER TABLE api_tokens ADD COLUMN IF NOT EXISTS role VARCHAR(20) NOT NULL DEFAULT 'admin';
+ALTER TABLE api_tokens ALTER COLUMN role SET DEFAULT 'viewer';
+ALTER TABLE api_tokens DROP CONSTRAINT IF EXISTS api_tokens_role_check;
+ALTER TABLE api_tokens
+    ADD CONSTRAINT api_tokens_role_check CHECK (role IN ('viewer', 'operator', 'admin'));
@@ -0,0 +1,23 @@
+-- =============================================================================
+-- 설정 변경 감사 로그
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- 감사 로그 테이블
+-- WebSocket API로 변경된 저장소/Jenkins 매핑/시스템 설정 기록
+-- actor는 API 토큰 이름, before/after는 변경 전후 행 스냅샷 (없으면 NULL)
+-- -----------------------------------------------------------------------------
+CREATE TABLE IF NOT EXISTS audit_log (
+    id SERIAL PRIMARY KEY,
+    actor VARCHAR(255),
+    client_id VARCHAR(36) NOT NULL,
+    remote_addr VARCHAR(64),
+    message_type VARCHAR(50) NOT NULL,
+    payload JSONB NOT NULL,
+    before_snapshot JSONB,
+    after_snapshot JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_log_created_at ON audit_log(created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_audit_log_actor ON audit_log(actor, created_at DESC);
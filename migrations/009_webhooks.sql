@@ -0,0 +1,14 @@
+-- =============================================================================
+-- GitHub 웹훅 수신
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- 저장소별 웹훅 서명 비밀값 (X-Hub-Signature-256 HMAC 검증용)
+-- 설정된 저장소는 웹훅으로 변경을 받고, 폴링은 느린 주기의 보정용으로만 실행
+-- -----------------------------------------------------------------------------
+ALTER TABLE repositories ADD COLUMN IF NOT EXISTS webhook_secret VARCHAR(255);
+
+-- 웹훅 사용 저장소의 보정 폴링 주기
+INSERT INTO system_settings (key, value, description) VALUES
+    ('webhook_reconcile_interval', '3600', '웹훅 사용 저장소의 보정 폴링 주기 (초)')
+ON CONFLICT (key) DO NOTHING;
@@ -0,0 +1,30 @@
+-- =============================================================================
+-- 웹훅 전송 기록
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- 웹훅 전송 기록 테이블
+-- 수신한 모든 웹훅 요청을 서명 검증/처리 결과와 함께 저장 (재처리 요청 포함)
+-- signature_valid는 저장소를 찾지 못했거나 비밀값이 없어 검증하지 않은 경우 NULL
+-- outcome: processed, ignored, rejected (요청 거부), failed (처리 중 에러)
+-- -----------------------------------------------------------------------------
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+    id SERIAL PRIMARY KEY,
+    delivery_guid VARCHAR(64),
+    event VARCHAR(50),
+    repository_id INTEGER REFERENCES repositories(id) ON DELETE SET NULL,
+    headers JSONB NOT NULL,
+    body TEXT NOT NULL,
+    signature_valid BOOLEAN,
+    outcome VARCHAR(20) NOT NULL
+        CONSTRAINT webhook_deliveries_outcome_check
+        CHECK (outcome IN ('processed', 'ignored', 'rejected', 'failed')),
+    error_message TEXT,
+    replay_of INTEGER REFERENCES webhook_deliveries(id) ON DELETE SET NULL,
+    received_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_received_at
+    ON webhook_deliveries(received_at DESC);
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_repository
+    ON webhook_deliveries(repository_id, received_at DESC);
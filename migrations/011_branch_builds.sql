@@ -0,0 +1,35 @@
+-- =============================================================================
+-- 브랜치 push 빌드
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- 브랜치 감시 규칙
+-- 이름 또는 glob 패턴 (예: "main", "release/*")에 맞는 브랜치의 head가 바뀌면
+-- 저장소의 Jenkins 매핑으로 빌드 트리거
+-- -----------------------------------------------------------------------------
+CREATE TABLE IF NOT EXISTS branch_watch_rules (
+    id SERIAL PRIMARY KEY,
+    repository_id INTEGER NOT NULL REFERENCES repositories(id) ON DELETE CASCADE,
+    pattern VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    UNIQUE(repository_id, pattern)
+);
+
+-- -----------------------------------------------------------------------------
+-- 빌드 트리거 대상: PR 또는 브랜치 중 하나
+-- 삭제된 브랜치의 빌드 이력은 브랜치와 함께 삭제
+-- -----------------------------------------------------------------------------
+ALTER TABLE build_triggers ALTER COLUMN pull_request_id DROP NOT NULL;
+ALTER TABLE build_triggers
+    ADD COLUMN IF NOT EXISTS branch_id INTEGER REFERENCES branches(id) ON DELETE CASCADE;
+
+ALTER TABLE build_triggers DROP CONSTRAINT IF EXISTS build_triggers_target_check;
+ALTER TABLE build_triggers
+    ADD CONSTRAINT build_triggers_target_check
+    CHECK ((pull_request_id IS NULL) <> (branch_id IS NULL));
+
+-- 같은 브랜치의 같은 커밋은 시도 번호당 한 번만 기록
+ALTER TABLE build_triggers DROP CONSTRAINT IF EXISTS build_triggers_branch_id_commit_sha_attempt_key;
+ALTER TABLE build_triggers
+    ADD CONSTRAINT build_triggers_branch_id_commit_sha_attempt_key
+    UNIQUE (branch_id, commit_sha, attempt);
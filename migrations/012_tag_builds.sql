@@ -0,0 +1,41 @@
+-- =============================================================================
+-- 태그 릴리스 빌드
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- 태그 규칙
+-- 이름 또는 glob 패턴 (예: "v*")에 맞는 태그가 새로 생기면 지정한 Jenkins job을
+-- 파라미터와 함께 트리거. 여러 규칙이 맞으면 먼저 등록된 규칙 하나만 적용
+-- jenkins_mapping_id: Jenkins 서버와 커밋 상태 context를 가져올 저장소 매핑
+--   (추가 시 지정하지 않으면 자동 트리거가 켜진 매핑 중 id가 가장 작은 매핑)
+-- parameters: Jenkins 빌드 파라미터 (문자열 값만 갖는 JSON 객체)
+-- -----------------------------------------------------------------------------
+CREATE TABLE IF NOT EXISTS tag_rules (
+    id SERIAL PRIMARY KEY,
+    repository_id INTEGER NOT NULL REFERENCES repositories(id) ON DELETE CASCADE,
+    jenkins_mapping_id INTEGER NOT NULL REFERENCES jenkins_mappings(id) ON DELETE CASCADE,
+    pattern VARCHAR(255) NOT NULL,
+    jenkins_job_name VARCHAR(255) NOT NULL,
+    parameters JSONB NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    UNIQUE(repository_id, pattern)
+);
+
+-- -----------------------------------------------------------------------------
+-- 빌드 트리거 대상: PR, 브랜치, 태그 중 하나
+-- jenkins_job_name: 저장소 매핑과 다른 job으로 빌드한 경우의 job 이름
+-- -----------------------------------------------------------------------------
+ALTER TABLE build_triggers
+    ADD COLUMN IF NOT EXISTS tag_id INTEGER REFERENCES tags(id) ON DELETE CASCADE;
+ALTER TABLE build_triggers ADD COLUMN IF NOT EXISTS jenkins_job_name VARCHAR(255);
+
+ALTER TABLE build_triggers DROP CONSTRAINT IF EXISTS build_triggers_target_check;
+ALTER TABLE build_triggers
+    ADD CONSTRAINT build_triggers_target_check
+    CHECK (num_nonnulls(pull_request_id, branch_id, tag_id) = 1);
+
+-- 같은 태그의 같은 커밋은 시도 번호당 한 번만 기록
+ALTER TABLE build_triggers DROP CONSTRAINT IF EXISTS build_triggers_tag_id_commit_sha_attempt_key;
+ALTER TABLE build_triggers
+    ADD CONSTRAINT build_triggers_tag_id_commit_sha_attempt_key
+    UNIQUE (tag_id, commit_sha, attempt);
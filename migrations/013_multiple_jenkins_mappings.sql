@@ -0,0 +1,65 @@
+-- =============================================================================
+-- 저장소당 여러 Jenkins 매핑
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- 매핑별 트리거 조건 (모두 비어 있으면 모든 PR에 적용)
+-- target_branch_patterns: PR 대상 브랜치 glob 패턴 (브랜치 push는 push된 브랜치)
+-- source_branch_patterns: PR source 브랜치 glob 패턴
+-- path_patterns: 변경 파일 경로 glob 패턴 (하나라도 맞는 파일이 있어야 함)
+-- required_labels: PR 라벨 (하나라도 붙어 있어야 함)
+-- 각 목록은 비어 있으면 조건 없음이며, 지정된 조건은 모두 만족해야 트리거
+-- 커밋 상태 context는 매핑마다 달라야 PR 체크 목록에서 구분됨
+-- -----------------------------------------------------------------------------
+ALTER TABLE jenkins_mappings DROP CONSTRAINT IF EXISTS jenkins_mappings_repository_id_key;
+
+ALTER TABLE jenkins_mappings
+    ADD COLUMN IF NOT EXISTS target_branch_patterns TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN IF NOT EXISTS source_branch_patterns TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN IF NOT EXISTS path_patterns TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN IF NOT EXISTS required_labels TEXT[] NOT NULL DEFAULT '{}';
+
+ALTER TABLE jenkins_mappings
+    DROP CONSTRAINT IF EXISTS jenkins_mappings_repository_id_status_context_key;
+ALTER TABLE jenkins_mappings
+    ADD CONSTRAINT jenkins_mappings_repository_id_status_context_key
+    UNIQUE (repository_id, status_context);
+
+CREATE INDEX IF NOT EXISTS idx_jenkins_mappings_repository ON jenkins_mappings(repository_id);
+
+-- -----------------------------------------------------------------------------
+-- PR 라벨 (라벨 조건 판단용)
+-- -----------------------------------------------------------------------------
+ALTER TABLE pull_requests ADD COLUMN IF NOT EXISTS labels TEXT[] NOT NULL DEFAULT '{}';
+
+-- -----------------------------------------------------------------------------
+-- 빌드 트리거를 실행한 매핑
+-- 같은 대상의 같은 커밋은 매핑마다 시도 번호당 한 번만 기록
+-- 매핑이 삭제되면 이력은 남기고 매핑 참조만 해제
+-- -----------------------------------------------------------------------------
+ALTER TABLE build_triggers
+    ADD COLUMN IF NOT EXISTS jenkins_mapping_id INTEGER
+    REFERENCES jenkins_mappings(id) ON DELETE SET NULL;
+
+-- 기존 이력은 저장소의 유일한 매핑으로 채움
+UPDATE build_triggers t SET jenkins_mapping_id = m.id
+FROM jenkins_mappings m
+WHERE t.jenkins_mapping_id IS NULL
+  AND m.repository_id = COALESCE(
+      (SELECT repository_id FROM pull_requests WHERE id = t.pull_request_id),
+      (SELECT repository_id FROM branches WHERE id = t.branch_id),
+      (SELECT repository_id FROM tags WHERE id = t.tag_id));
+
+ALTER TABLE build_triggers DROP CONSTRAINT IF EXISTS build_triggers_pull_request_id_commit_sha_attempt_key;
+ALTER TABLE build_triggers
+    DROP CONSTRAINT IF EXISTS build_triggers_pull_request_id_mapping_commit_sha_attempt_key;
+ALTER TABLE build_triggers
+    ADD CONSTRAINT build_triggers_pull_request_id_mapping_commit_sha_attempt_key
+    UNIQUE (pull_request_id, jenkins_mapping_id, commit_sha, attempt);
+
+ALTER TABLE build_triggers DROP CONSTRAINT IF EXISTS build_triggers_branch_id_commit_sha_attempt_key;
+ALTER TABLE build_triggers
+    DROP CONSTRAINT IF EXISTS build_triggers_branch_id_mapping_commit_sha_attempt_key;
+ALTER TABLE build_triggers
+    ADD CONSTRAINT build_triggers_branch_id_mapping_commit_sha_attempt_key
+    UNIQUE (branch_id, jenkins_mapping_id, commit_sha, attempt);
@@ -0,0 +1,12 @@
+-- =============================================================================
+-- Jenkins 매핑 빌드 파라미터
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- parameters: Jenkins `buildWithParameters`로 보낼 파라미터 (문자열 값만 갖는 JSON 객체)
+-- 값에는 트리거 시점에 채워지는 `{{pr_number}}`, `{{head_sha}}`, `{{source_branch}}`,
+-- `{{target_branch}}`, `{{author}}`, `{{repo_owner}}`, `{{repo_name}}` 사용 가능
+-- (예: {"PR_REF": "pull/{{pr_number}}/head", "REPO": "{{repo_owner}}/{{repo_name}}"})
+-- -----------------------------------------------------------------------------
+ALTER TABLE jenkins_mappings
+    ADD COLUMN IF NOT EXISTS parameters JSONB NOT NULL DEFAULT '{}';
@@ -0,0 +1,29 @@
+-- =============================================================================
+-- 자동 PR 빌드 건너뛰기/디바운스/이전 빌드 중단 설정
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- debounce_seconds: 새 커밋 감지 후 대기 시간. 대기 중 더 새 커밋이 올라오면 건너뜀
+-- skip_draft: 초안(draft) PR은 빌드하지 않음 (리뷰 준비 완료로 바뀌면 빌드)
+-- skip_ci_commits: 커밋 메시지에 `[skip ci]`가 있으면 빌드하지 않음
+-- cancel_previous: 새 커밋 빌드 시 같은 PR의 진행 중인 이전 커밋 빌드 중단
+-- 각 결정은 build_triggers.trigger_message에 기록
+-- -----------------------------------------------------------------------------
+ALTER TABLE jenkins_mappings
+    ADD COLUMN IF NOT EXISTS debounce_seconds INTEGER NOT NULL DEFAULT 0
+        CHECK (debounce_seconds BETWEEN 0 AND 3600),
+    ADD COLUMN IF NOT EXISTS skip_draft BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN IF NOT EXISTS skip_ci_commits BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN IF NOT EXISTS cancel_previous BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- -----------------------------------------------------------------------------
+-- 건너뛴 트리거(skipped)의 사유 (건너뛰지 않은 트리거는 NULL)
+-- superseded: 디바운스 중 새 커밋, closed: 디바운스 중 PR 닫힘,
+-- draft: 초안 PR (리뷰 준비 완료 시 재트리거 대상), skip_ci: `[skip ci]` 커밋
+-- -----------------------------------------------------------------------------
+ALTER TABLE build_triggers ADD COLUMN IF NOT EXISTS skip_reason VARCHAR(20);
+
+ALTER TABLE build_triggers DROP CONSTRAINT IF EXISTS build_triggers_skip_reason_check;
+ALTER TABLE build_triggers
+    ADD CONSTRAINT build_triggers_skip_reason_check
+    CHECK (skip_reason IN ('superseded', 'closed', 'draft', 'skip_ci'));
@@ -0,0 +1,40 @@
+-- =============================================================================
+-- PR 상태 전이
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- PR 상태 제한
+-- open: 열림, draft: 초안, closed: 머지 없이 닫힘, merged: 머지됨,
+-- reopened: 닫혔다가 다시 열림 (다시 닫히거나 초안이 될 때까지 유지)
+-- 기존 초안 PR은 다음 폴링에서 draft 상태로 바뀜
+-- -----------------------------------------------------------------------------
+UPDATE pull_requests SET status = 'closed'
+    WHERE status NOT IN ('open', 'draft', 'closed', 'merged', 'reopened');
+
+ALTER TABLE pull_requests DROP CONSTRAINT IF EXISTS pull_requests_status_check;
+ALTER TABLE pull_requests
+    ADD CONSTRAINT pull_requests_status_check
+    CHECK (status IN ('open', 'draft', 'closed', 'merged', 'reopened'));
+
+-- -----------------------------------------------------------------------------
+-- PR 상태 전이 이력
+-- from_status: 이전 상태 (처음 감지된 PR이면 NULL)
+-- head_sha: 전이 시점의 head 커밋
+-- -----------------------------------------------------------------------------
+CREATE TABLE IF NOT EXISTS pull_request_transitions (
+    id SERIAL PRIMARY KEY,
+    pull_request_id INTEGER NOT NULL REFERENCES pull_requests(id) ON DELETE CASCADE,
+    from_status VARCHAR(50)
+        CHECK (from_status IN ('open', 'draft', 'closed', 'merged', 'reopened')),
+    to_status VARCHAR(50) NOT NULL
+        CHECK (to_status IN ('open', 'draft', 'closed', 'merged', 'reopened')),
+    head_sha VARCHAR(64) NOT NULL,
+    changed_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_pull_request_transitions_pull_request_id
+    ON pull_request_transitions (pull_request_id, changed_at);
+
+-- 기존 PR은 현재 상태를 첫 전이로 기록
+INSERT INTO pull_request_transitions (pull_request_id, from_status, to_status, head_sha, changed_at)
+SELECT id, NULL, status, head_sha, created_at FROM pull_requests;
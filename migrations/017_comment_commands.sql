@@ -0,0 +1,49 @@
+-- =============================================================================
+-- PR 코멘트 명령
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- 명령 이름 (빈 값이면 해당 명령 비활성화)
+-- -----------------------------------------------------------------------------
+INSERT INTO system_settings (key, value, description) VALUES
+    ('comment_command_retest', '/retest', '매핑된 Jenkins 잡 전체를 다시 빌드하는 코멘트 명령'),
+    ('comment_command_build', '/build', '지정한 Jenkins 잡만 빌드하는 코멘트 명령 (예: /build my-job)'),
+    ('comment_command_skip', '/skip', '현재 head 커밋의 빌드를 건너뛰는 코멘트 명령')
+ON CONFLICT (key) DO NOTHING;
+
+-- -----------------------------------------------------------------------------
+-- 폴링으로 코멘트를 마지막으로 조회한 시각 (NULL이면 아직 조회하지 않음)
+-- 첫 조회 시에는 기존 코멘트를 처리하지 않고 시각만 기록
+-- -----------------------------------------------------------------------------
+ALTER TABLE repositories ADD COLUMN IF NOT EXISTS comments_polled_at TIMESTAMPTZ;
+
+-- -----------------------------------------------------------------------------
+-- 처리한 코멘트 명령
+-- comment_id: GitHub 코멘트 ID (웹훅과 폴링이 같은 코멘트를 중복 처리하지 않도록 UNIQUE)
+-- result: pending(처리 중), triggered(빌드 요청), skipped(빌드 건너뜀),
+--         denied(쓰기 권한 없음), rejected(실행할 수 없는 명령), failed(처리 실패)
+-- -----------------------------------------------------------------------------
+CREATE TABLE IF NOT EXISTS pull_request_commands (
+    id SERIAL PRIMARY KEY,
+    pull_request_id INTEGER NOT NULL REFERENCES pull_requests(id) ON DELETE CASCADE,
+    comment_id BIGINT NOT NULL UNIQUE,
+    author VARCHAR(255) NOT NULL,
+    command VARCHAR(50) NOT NULL CHECK (command IN ('retest', 'build', 'skip')),
+    argument VARCHAR(255),
+    result VARCHAR(50) NOT NULL DEFAULT 'pending'
+        CHECK (result IN ('pending', 'triggered', 'skipped', 'denied', 'rejected', 'failed')),
+    message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_pull_request_commands_pull_request_id
+    ON pull_request_commands (pull_request_id, created_at);
+
+-- -----------------------------------------------------------------------------
+-- `/skip` 명령으로 건너뛴 트리거의 사유 추가 (command)
+-- -----------------------------------------------------------------------------
+ALTER TABLE build_triggers DROP CONSTRAINT IF EXISTS build_triggers_skip_reason_check;
+ALTER TABLE build_triggers
+    ADD CONSTRAINT build_triggers_skip_reason_check
+    CHECK (skip_reason IN ('superseded', 'closed', 'draft', 'skip_ci', 'command'));
@@ -0,0 +1,11 @@
+-- =============================================================================
+-- 라벨 기반 트리거 제한
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- excluded_labels: 하나라도 붙어 있으면 트리거하지 않는 PR 라벨 (예: wip)
+-- required_labels와 함께 지정하면 두 조건을 모두 만족해야 트리거
+-- 필요한 라벨이 추가되거나 제외 라벨이 제거되면 head 커밋이 그대로여도 트리거
+-- -----------------------------------------------------------------------------
+ALTER TABLE jenkins_mappings
+    ADD COLUMN IF NOT EXISTS excluded_labels TEXT[] NOT NULL DEFAULT '{}';
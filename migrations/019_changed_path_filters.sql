@@ -0,0 +1,18 @@
+-- =============================================================================
+-- 변경 파일 경로 조건
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- excluded_path_patterns: 조건 판단에서 제외할 변경 파일 경로 glob 패턴 (예: docs/**)
+-- path_patterns(포함)에 맞고 제외 패턴에 맞지 않는 파일이 하나라도 있어야 트리거
+-- (포함 패턴이 비어 있으면 모든 파일이 포함 대상)
+-- 경로 조건이 있는 매핑은 변경 파일 목록이 없는 감시 브랜치 push로는 트리거하지 않음
+-- -----------------------------------------------------------------------------
+ALTER TABLE jenkins_mappings
+    ADD COLUMN IF NOT EXISTS excluded_path_patterns TEXT[] NOT NULL DEFAULT '{}';
+
+-- -----------------------------------------------------------------------------
+-- 빌드 트리거가 일치한 변경 파일 경로 (추적용)
+-- 경로 조건이 없는 매핑이나 브랜치/태그 빌드는 NULL
+-- -----------------------------------------------------------------------------
+ALTER TABLE build_triggers ADD COLUMN IF NOT EXISTS matched_paths TEXT[];
@@ -0,0 +1,15 @@
+-- =============================================================================
+-- 브랜치/태그 동기화 기준점
+-- =============================================================================
+
+-- -----------------------------------------------------------------------------
+-- 첫 동기화를 끝까지 완료한 시각 (NULL이면 아직 기준점 없음)
+-- 기준점이 없는 동기화는 기존 브랜치/태그를 빌드하지 않으므로, 첫 동기화가 중간에
+-- 실패해도 다음 동기화에서 나머지 브랜치/태그가 새로 생긴 것으로 빌드되지 않음
+-- 이미 브랜치가 동기화된 저장소는 기준점이 있는 것으로 간주
+-- -----------------------------------------------------------------------------
+ALTER TABLE repositories ADD COLUMN IF NOT EXISTS refs_baselined_at TIMESTAMPTZ;
+
+UPDATE repositories SET refs_baselined_at = NOW()
+WHERE refs_baselined_at IS NULL
+  AND EXISTS (SELECT 1 FROM branches WHERE branches.repository_id = repositories.id);
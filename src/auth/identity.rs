@@ -0,0 +1,26 @@
+use crate::db::{ApiToken, Role};
+use serde::Serialize;
+
+/// 인증된 연결의 주체 (연결에 사용된 API 토큰)
+#[derive(Debug, Clone, Serialize)]
+pub struct Identity {
+    pub token_id: i32,
+    pub name: String,
+    pub role: Role,
+}
+
+impl Identity {
+    /// 토큰의 역할 값이 올바르지 않으면 None
+    pub fn from_token(token: &ApiToken) -> Option<Self> {
+        Some(Identity {
+            token_id: token.id,
+            name: token.name.clone(),
+            role: Role::parse(&token.role)?,
+        })
+    }
+
+    /// `required` 이상의 역할인지 확인
+    pub fn has_role(&self, required: Role) -> bool {
+        self.role >= required
+    }
+}
@@ -0,0 +1,7 @@
+mod identity;
+mod permission;
+mod token;
+
+pub use identity::*;
+pub use permission::*;
+pub use token::*;
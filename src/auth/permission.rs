@@ -0,0 +1,55 @@
+//! WebSocket 명령별 권한 테이블
+//!
+//! 모든 명령의 필요 역할을 한 곳에서 정의하고 `WsServer`가 핸들러 호출 전에 검사
+
+use crate::{db::Role, ws::ws_message::ClientMessageType};
+
+/// 명령 실행에 필요한 최소 역할
+///
+/// None이면 인증 없이 실행 가능 (인증 명령만 해당)
+pub fn required_role(message: &ClientMessageType) -> Option<Role> {
+    match message {
+        ClientMessageType::Authenticate { .. } => None,
+
+        // 이벤트 구독, 조회
+        ClientMessageType::Subscribe { .. }
+        | ClientMessageType::Unsubscribe { .. }
+        | ClientMessageType::GetSubscriptions
+        | ClientMessageType::GetRepositories
+        | ClientMessageType::GetRepository { .. }
+        | ClientMessageType::GetPullRequests { .. }
+        | ClientMessageType::GetPullRequestHistory { .. }
+        | ClientMessageType::GetBuildHistory { .. }
+        | ClientMessageType::GetJenkinsMappings { .. }
+        | ClientMessageType::GetBranches { .. }
+        | ClientMessageType::GetBranchBuildHistory { .. }
+        | ClientMessageType::GetBranchWatchRules { .. }
+        | ClientMessageType::GetTags { .. }
+        | ClientMessageType::GetTagBuildHistory { .. }
+        | ClientMessageType::GetTagRules { .. }
+        | ClientMessageType::GetSystemSettings
+        | ClientMessageType::GetPollingHistory { .. } => Some(Role::Viewer),
+
+        // 저장소/Jenkins 매핑/감시 규칙 관리, 빌드 트리거, 웹훅 전송 기록 조회/재처리
+        // (Jenkins 매핑의 jenkins_url 변경은 핸들러에서 Admin 확인)
+        ClientMessageType::AddRepository { .. }
+        | ClientMessageType::UpdateRepository { .. }
+        | ClientMessageType::SetJenkinsMapping { .. }
+        | ClientMessageType::DeleteJenkinsMapping { .. }
+        | ClientMessageType::AddBranchWatchRule { .. }
+        | ClientMessageType::DeleteBranchWatchRule { .. }
+        | ClientMessageType::AddTagRule { .. }
+        | ClientMessageType::DeleteTagRule { .. }
+        | ClientMessageType::TriggerBuild { .. }
+        | ClientMessageType::ReplayWebhookDelivery { .. }
+        | ClientMessageType::GetWebhookDeliveries { .. } => Some(Role::Operator),
+
+        // 시스템 설정, 저장소 삭제, API 토큰 관리, 감사 로그 조회
+        ClientMessageType::DeleteRepository { .. }
+        | ClientMessageType::UpdateSystemSetting { .. }
+        | ClientMessageType::CreateApiToken { .. }
+        | ClientMessageType::RevokeApiToken { .. }
+        | ClientMessageType::GetApiTokens
+        | ClientMessageType::GetAuditLog { .. } => Some(Role::Admin),
+    }
+}
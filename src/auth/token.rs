@@ -0,0 +1,43 @@
+//! WebSocket 관리 API 토큰 인증
+//!
+//! 토큰 원문은 생성 시 한 번만 반환하고 DB에는 SHA-256 해시만 저장
+
+use crate::{auth::Identity, db};
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+
+/// 발급 토큰 접두사 (로그/설정 파일에서 토큰 식별용)
+const TOKEN_PREFIX: &str = "prb_";
+
+/// 새 API 토큰 원문 생성 (256비트 난수)
+pub fn generate_token() -> String {
+    let bytes: [u8; 32] = rand::random();
+    format!("{}{}", TOKEN_PREFIX, hex::encode(bytes))
+}
+
+/// 토큰 원문의 SHA-256 해시 (hex)
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+/// `Authorization: Bearer <token>` 헤더 값에서 토큰 추출
+pub fn bearer_token(header: &str) -> Option<&str> {
+    let (scheme, token) = header.trim().split_once(' ')?;
+    let token = token.trim();
+    (scheme.eq_ignore_ascii_case("bearer") && !token.is_empty()).then_some(token)
+}
+
+/// 토큰 검증
+///
+/// # Returns
+///
+/// 등록되지 않았거나 폐기된 토큰이면 None
+pub async fn authenticate(pool: &PgPool, token: &str) -> Result<Option<Identity>> {
+    let Some(api_token) = db::Queries::use_api_token(pool, &hash_token(token)).await? else {
+        return Ok(None);
+    };
+    let identity = Identity::from_token(&api_token)
+        .ok_or_else(|| anyhow::anyhow!("Invalid role for api token {}", api_token.id))?;
+    Ok(Some(identity))
+}
@@ -11,12 +11,24 @@ pub struct Config {
     #[serde(default = "default_port")]
     pub server_port: u16,
 
+    /// GitHub 웹훅 수신 포트
+    #[serde(default = "default_webhook_port")]
+    pub webhook_port: u16,
+
+    /// 초기 관리자(admin) API 토큰 (시작 시 해시로 등록, 이후 WebSocket API로 토큰 발급)
+    #[serde(default)]
+    pub api_token: Option<String>,
+
     // Database
     pub database_url: String,
 
     // GitHub
     pub github_token: Option<String>,
 
+    /// GitHub API 주소 (GitHub Enterprise 사용 시 변경)
+    #[serde(default = "default_github_api_url")]
+    pub github_api_url: String,
+
     // Git SSH (선택적)
     #[serde(default)]
     pub git_ssh_key_path: Option<PathBuf>,
@@ -41,6 +53,14 @@ fn default_port() -> u16 {
     8080
 }
 
+fn default_webhook_port() -> u16 {
+    8081
+}
+
+fn default_github_api_url() -> String {
+    "https://api.github.com".to_string()
+}
+
 fn default_use_ssh_agent() -> bool {
     false
 }
@@ -69,4 +89,8 @@ impl Config {
     pub fn server_addr(&self) -> String {
         format!("{}:{}", self.server_host, self.server_port)
     }
+
+    pub fn webhook_addr(&self) -> String {
+        format!("{}:{}", self.server_host, self.webhook_port)
+    }
 }
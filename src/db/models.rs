@@ -1,5 +1,6 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use sqlx::FromRow;
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
@@ -11,6 +12,19 @@ pub struct Repository {
     pub is_active: bool,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    /// 웹훅 서명 비밀값 (설정 시 폴링은 보정 주기로만 실행, 응답에는 포함하지 않음)
+    #[serde(skip_serializing)]
+    pub webhook_secret: Option<String>,
+    /// 폴링으로 PR 코멘트를 마지막으로 조회한 시각 (None이면 아직 조회하지 않음)
+    pub comments_polled_at: Option<DateTime<Utc>>,
+    /// 브랜치/태그 첫 동기화를 완료한 시각 (None이면 다음 동기화는 기준점 기록만 수행)
+    pub refs_baselined_at: Option<DateTime<Utc>>,
+}
+
+impl Repository {
+    pub fn webhook_enabled(&self) -> bool {
+        self.webhook_secret.is_some()
+    }
 }
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
@@ -31,6 +45,44 @@ pub struct Branch {
     pub updated_at: NaiveDateTime,
 }
 
+/// 브랜치 감시 규칙 (이름 또는 glob 패턴)
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct BranchWatchRule {
+    pub id: i32,
+    pub repository_id: i32,
+    pub pattern: String,
+    pub created_at: NaiveDateTime,
+}
+
+/// 태그 규칙 (패턴에 맞는 새 태그를 지정한 job으로 빌드)
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct TagRule {
+    pub id: i32,
+    pub repository_id: i32,
+    /// Jenkins 서버와 커밋 상태 context를 가져올 저장소 매핑
+    pub jenkins_mapping_id: i32,
+    pub pattern: String,
+    pub jenkins_job_name: String,
+    /// Jenkins 빌드 파라미터 (문자열 값만 갖는 JSON 객체)
+    pub parameters: Value,
+    pub created_at: NaiveDateTime,
+}
+
+impl TagRule {
+    /// Jenkins `buildWithParameters` 요청 파라미터
+    pub fn build_parameters(&self) -> Vec<(String, String)> {
+        self.parameters
+            .as_object()
+            .map(|params| {
+                params
+                    .iter()
+                    .filter_map(|(key, value)| Some((key.clone(), value.as_str()?.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct Tag {
     pub id: i32,
@@ -54,12 +106,139 @@ pub struct PullRequest {
     pub last_polled_at: Option<NaiveDateTime>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub labels: Vec<String>,
+}
+
+impl PullRequest {
+    /// 열린 상태 (`Open`, `Draft`, `Reopened`)
+    pub fn is_open(&self) -> bool {
+        PrStatus::parse(&self.status).is_some_and(|status| status.is_open())
+    }
+
+    pub fn is_draft(&self) -> bool {
+        self.status == PrStatus::Draft.as_str()
+    }
+}
+
+/// PR 상태 (`pull_requests.status`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrStatus {
+    Open,
+    /// 초안
+    Draft,
+    /// 머지 없이 닫힘
+    Closed,
+    Merged,
+    /// 닫혔다가 다시 열림 (다시 닫히거나 초안이 될 때까지 유지)
+    Reopened,
+}
+
+impl PrStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PrStatus::Open => "open",
+            PrStatus::Draft => "draft",
+            PrStatus::Closed => "closed",
+            PrStatus::Merged => "merged",
+            PrStatus::Reopened => "reopened",
+        }
+    }
+
+    /// `status` 문자열을 상태 값으로 변환
+    pub fn parse(value: &str) -> Option<Self> {
+        [
+            PrStatus::Open,
+            PrStatus::Draft,
+            PrStatus::Closed,
+            PrStatus::Merged,
+            PrStatus::Reopened,
+        ]
+        .into_iter()
+        .find(|status| status.as_str() == value)
+    }
+
+    pub fn is_open(&self) -> bool {
+        matches!(self, PrStatus::Open | PrStatus::Draft | PrStatus::Reopened)
+    }
+
+    /// GitHub에서 확인한 상태(`Open`, `Draft`, `Closed`, `Merged`)와 이전 상태로 다음 상태 계산
+    ///
+    /// 닫혀 있던 PR이 다시 열리면 `Reopened`가 되고, 이후 열린 상태로 바뀌는 동안 유지
+    pub fn next(previous: Option<PrStatus>, observed: PrStatus) -> PrStatus {
+        match (previous, observed) {
+            (Some(PrStatus::Closed | PrStatus::Reopened), PrStatus::Open) => PrStatus::Reopened,
+            (_, observed) => observed,
+        }
+    }
 }
 
+/// PR 상태 전이 이력
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
-pub struct BuildTrigger {
+pub struct PullRequestTransition {
     pub id: i32,
     pub pull_request_id: i32,
+    /// 이전 상태 (처음 감지된 PR이면 None)
+    pub from_status: Option<String>,
+    pub to_status: String,
+    /// 전이 시점의 head 커밋
+    pub head_sha: String,
+    pub changed_at: NaiveDateTime,
+}
+
+/// PR 코멘트로 받은 명령 처리 기록
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct PullRequestCommand {
+    pub id: i32,
+    pub pull_request_id: i32,
+    /// GitHub 코멘트 ID
+    pub comment_id: i64,
+    /// 코멘트 작성자
+    pub author: String,
+    /// `retest`, `build`, `skip`
+    pub command: String,
+    /// `build` 명령의 잡 이름
+    pub argument: Option<String>,
+    /// `CommandResult` 값
+    pub result: String,
+    pub message: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+/// `pull_request_commands.result` 값
+///
+/// 처리 중인 기록은 DB 기본값 `pending`으로 남음
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandResult {
+    /// 빌드 요청
+    Triggered,
+    /// 빌드 건너뜀
+    Skipped,
+    /// 작성자에게 쓰기 권한 없음
+    Denied,
+    /// 실행할 수 없는 명령 (알 수 없는 잡, 닫힌 PR 등)
+    Rejected,
+    /// 처리 중 오류
+    Failed,
+}
+
+impl CommandResult {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommandResult::Triggered => "triggered",
+            CommandResult::Skipped => "skipped",
+            CommandResult::Denied => "denied",
+            CommandResult::Rejected => "rejected",
+            CommandResult::Failed => "failed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct BuildTrigger {
+    pub id: i32,
+    /// PR 빌드 (`branch_id`, `tag_id`와 셋 중 하나만 설정)
+    pub pull_request_id: Option<i32>,
     pub commit_sha: String,
     pub jenkins_build_number: Option<i32>,
     pub jenkins_build_url: Option<String>,
@@ -67,6 +246,172 @@ pub struct BuildTrigger {
     pub trigger_message: Option<String>,
     pub triggered_at: NaiveDateTime,
     pub completed_at: Option<NaiveDateTime>,
+    pub jenkins_queue_url: Option<String>,
+    pub build_duration_ms: Option<i64>,
+    /// 같은 PR/커밋의 빌드 시도 번호 (자동 트리거는 1)
+    pub attempt: i32,
+    /// 브랜치 push 빌드 (`pull_request_id`, `tag_id`와 셋 중 하나만 설정)
+    pub branch_id: Option<i32>,
+    /// 태그 릴리스 빌드 (`pull_request_id`, `branch_id`와 셋 중 하나만 설정)
+    pub tag_id: Option<i32>,
+    /// 저장소 매핑과 다른 job으로 빌드한 경우의 job 이름 (태그 규칙 등)
+    pub jenkins_job_name: Option<String>,
+    /// 빌드를 실행한 Jenkins 매핑 (매핑이 삭제되면 None)
+    pub jenkins_mapping_id: Option<i32>,
+    /// 매핑의 경로 조건에 일치한 변경 파일 (경로 조건이 없으면 None)
+    pub matched_paths: Option<Vec<String>>,
+    /// 건너뛴 트리거의 `SkipReason` 값 (건너뛰지 않았으면 None)
+    pub skip_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ApiToken {
+    pub id: i32,
+    /// 토큰 이름 (발급 대상 식별용, 해시는 조회하지 않음)
+    pub name: String,
+    pub last_used_at: Option<NaiveDateTime>,
+    pub revoked_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    /// `Role` 값 ("viewer", "operator", "admin")
+    pub role: String,
+}
+
+/// API 토큰 역할 (`api_tokens.role` 값)
+///
+/// 상위 역할은 하위 역할의 권한을 모두 포함
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// 조회
+    Viewer,
+    /// 저장소/Jenkins 매핑 관리, 빌드 트리거
+    Operator,
+    /// 시스템 설정, 저장소 삭제, API 토큰 관리, 감사 로그 조회
+    Admin,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Viewer => "viewer",
+            Role::Operator => "operator",
+            Role::Admin => "admin",
+        }
+    }
+
+    /// `role` 문자열을 역할 값으로 변환
+    pub fn parse(value: &str) -> Option<Self> {
+        [Role::Viewer, Role::Operator, Role::Admin]
+            .into_iter()
+            .find(|role| role.as_str() == value)
+    }
+}
+
+/// `build_triggers.trigger_status` 값
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerStatus {
+    /// 트리거 요청 전
+    Pending,
+    /// Jenkins 큐 대기 중 (빌드 번호 미확정)
+    Queued,
+    /// 빌드 번호 확정
+    Started,
+    /// 빌드 진행 중
+    Running,
+    /// 트리거 요청 실패
+    Failed,
+    /// 빌드 성공
+    Success,
+    /// 빌드 실패
+    Failure,
+    /// 테스트 실패 등 불안정
+    Unstable,
+    /// 빌드 중단
+    Aborted,
+    /// 자동 빌드 조건(초안, `[skip ci]`, 디바운스)에 따라 건너뜀
+    Skipped,
+}
+
+impl TriggerStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TriggerStatus::Pending => "pending",
+            TriggerStatus::Queued => "queued",
+            TriggerStatus::Started => "started",
+            TriggerStatus::Running => "running",
+            TriggerStatus::Failed => "failed",
+            TriggerStatus::Success => "success",
+            TriggerStatus::Failure => "failure",
+            TriggerStatus::Unstable => "unstable",
+            TriggerStatus::Aborted => "aborted",
+            TriggerStatus::Skipped => "skipped",
+        }
+    }
+
+    /// Jenkins 빌드 결과(`SUCCESS`, `FAILURE`, ...)를 완료 상태로 변환
+    pub fn from_jenkins_result(result: &str) -> Option<Self> {
+        match result {
+            "SUCCESS" => Some(TriggerStatus::Success),
+            "FAILURE" => Some(TriggerStatus::Failure),
+            "UNSTABLE" => Some(TriggerStatus::Unstable),
+            "ABORTED" | "NOT_BUILT" => Some(TriggerStatus::Aborted),
+            _ => None,
+        }
+    }
+
+    /// `trigger_status` 문자열을 상태 값으로 변환
+    pub fn parse(value: &str) -> Option<Self> {
+        [
+            TriggerStatus::Pending,
+            TriggerStatus::Queued,
+            TriggerStatus::Started,
+            TriggerStatus::Running,
+            TriggerStatus::Failed,
+            TriggerStatus::Success,
+            TriggerStatus::Failure,
+            TriggerStatus::Unstable,
+            TriggerStatus::Aborted,
+            TriggerStatus::Skipped,
+        ]
+        .into_iter()
+        .find(|status| status.as_str() == value)
+    }
+
+    /// 결과 확인이 필요한 상태 (`Queued`, `Started`, `Running`)
+    pub fn in_flight() -> [TriggerStatus; 3] {
+        [
+            TriggerStatus::Queued,
+            TriggerStatus::Started,
+            TriggerStatus::Running,
+        ]
+    }
+}
+
+/// 건너뛴 트리거의 사유 (`build_triggers.skip_reason` 값)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// 디바운스 대기 중 더 새 커밋이 올라옴
+    Superseded,
+    /// 디바운스 대기 중 PR이 닫힘
+    Closed,
+    /// 초안 PR (리뷰 준비 완료로 바뀌면 다시 트리거)
+    Draft,
+    /// 커밋 메시지에 `[skip ci]`가 있음
+    SkipCi,
+    /// `/skip` 코멘트 명령
+    Command,
+}
+
+impl SkipReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SkipReason::Superseded => "superseded",
+            SkipReason::Closed => "closed",
+            SkipReason::Draft => "draft",
+            SkipReason::SkipCi => "skip_ci",
+            SkipReason::Command => "command",
+        }
+    }
 }
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
@@ -78,6 +423,30 @@ pub struct JenkinsMapping {
     pub auto_trigger: bool,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    /// GitHub 커밋 상태 context 이름 (저장소 내 매핑마다 고유)
+    pub status_context: String,
+    /// PR 대상 브랜치 glob 패턴 (비어 있으면 조건 없음)
+    pub target_branch_patterns: Vec<String>,
+    /// PR source 브랜치 glob 패턴 (비어 있으면 조건 없음)
+    pub source_branch_patterns: Vec<String>,
+    /// 포함할 변경 파일 경로 glob 패턴 (비어 있으면 모든 파일)
+    pub path_patterns: Vec<String>,
+    /// 하나라도 붙어 있어야 하는 PR 라벨 (비어 있으면 조건 없음)
+    pub required_labels: Vec<String>,
+    /// Jenkins 빌드 파라미터 템플릿 (문자열 값만 갖는 JSON 객체)
+    pub parameters: Value,
+    /// 새 커밋 감지 후 자동 빌드 전 대기 시간 (초, 0이면 바로 빌드)
+    pub debounce_seconds: i32,
+    /// 초안 PR 자동 빌드 건너뛰기
+    pub skip_draft: bool,
+    /// 커밋 메시지에 `[skip ci]`가 있으면 자동 빌드 건너뛰기
+    pub skip_ci_commits: bool,
+    /// 새 커밋 빌드 시 같은 PR의 진행 중인 이전 빌드 중단
+    pub cancel_previous: bool,
+    /// 하나라도 붙어 있으면 트리거하지 않는 PR 라벨 (비어 있으면 조건 없음)
+    pub excluded_labels: Vec<String>,
+    /// 제외할 변경 파일 경로 glob 패턴 (예: `docs/**`)
+    pub excluded_path_patterns: Vec<String>,
 }
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
@@ -87,3 +456,122 @@ pub struct SystemSetting {
     pub description: Option<String>,
     pub updated_at: NaiveDateTime,
 }
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct AuditLog {
+    pub id: i32,
+    /// 변경한 API 토큰 이름
+    pub actor: Option<String>,
+    /// WebSocket 연결 ID
+    pub client_id: String,
+    pub remote_addr: Option<String>,
+    /// `ClientMessageType` 타입 이름 (예: "update_repository")
+    pub message_type: String,
+    /// 요청 데이터
+    pub payload: Value,
+    pub before_snapshot: Option<Value>,
+    pub after_snapshot: Option<Value>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    pub id: i32,
+    /// `X-GitHub-Delivery` 헤더 값
+    pub delivery_guid: Option<String>,
+    /// `X-GitHub-Event` 헤더 값
+    pub event: Option<String>,
+    pub repository_id: Option<i32>,
+    /// 요청 헤더 (이름은 소문자)
+    pub headers: Value,
+    /// 요청 본문 원문
+    pub body: String,
+    /// 서명 검증 결과 (검증하지 않았으면 None)
+    pub signature_valid: Option<bool>,
+    /// "processed", "ignored", "rejected", "failed"
+    pub outcome: String,
+    pub error_message: Option<String>,
+    /// 재처리한 원본 전송 기록 ID
+    pub replay_of: Option<i32>,
+    pub received_at: NaiveDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_observation_keeps_observed_status() {
+        for observed in [
+            PrStatus::Open,
+            PrStatus::Draft,
+            PrStatus::Closed,
+            PrStatus::Merged,
+        ] {
+            assert_eq!(PrStatus::next(None, observed), observed);
+        }
+    }
+
+    #[test]
+    fn reopening_closed_pull_request() {
+        assert_eq!(
+            PrStatus::next(Some(PrStatus::Closed), PrStatus::Open),
+            PrStatus::Reopened
+        );
+        // 다시 열린 상태는 열려 있는 동안 유지
+        assert_eq!(
+            PrStatus::next(Some(PrStatus::Reopened), PrStatus::Open),
+            PrStatus::Reopened
+        );
+    }
+
+    #[test]
+    fn reopened_ends_when_closed_or_drafted() {
+        assert_eq!(
+            PrStatus::next(Some(PrStatus::Reopened), PrStatus::Closed),
+            PrStatus::Closed
+        );
+        assert_eq!(
+            PrStatus::next(Some(PrStatus::Reopened), PrStatus::Draft),
+            PrStatus::Draft
+        );
+        assert_eq!(
+            PrStatus::next(Some(PrStatus::Reopened), PrStatus::Merged),
+            PrStatus::Merged
+        );
+        assert_eq!(
+            PrStatus::next(Some(PrStatus::Closed), PrStatus::Draft),
+            PrStatus::Draft
+        );
+    }
+
+    #[test]
+    fn open_and_draft_transitions() {
+        assert_eq!(
+            PrStatus::next(Some(PrStatus::Open), PrStatus::Draft),
+            PrStatus::Draft
+        );
+        assert_eq!(
+            PrStatus::next(Some(PrStatus::Draft), PrStatus::Open),
+            PrStatus::Open
+        );
+        assert_eq!(
+            PrStatus::next(Some(PrStatus::Open), PrStatus::Merged),
+            PrStatus::Merged
+        );
+    }
+
+    #[test]
+    fn status_strings_round_trip() {
+        for status in [
+            PrStatus::Open,
+            PrStatus::Draft,
+            PrStatus::Closed,
+            PrStatus::Merged,
+            PrStatus::Reopened,
+        ] {
+            assert_eq!(PrStatus::parse(status.as_str()), Some(status));
+        }
+        assert_eq!(PrStatus::parse("unknown"), None);
+    }
+}
@@ -4,8 +4,14 @@
 //! - DB 실행 후에도 SQLx 관련 경고가 있을 경우: rust-analyzer 재시작 필요
 //! - Ctrl+Shift+P → "rust-analyzer: Restart Server"
 
-use crate::db::{Repository, models::SystemSetting};
+use crate::db::{
+    ApiToken, AuditLog, Branch, BranchWatchRule, BuildTrigger, CommandResult, JenkinsMapping, PrStatus,
+    PullRequest, PullRequestCommand, PullRequestTransition, Repository, RepositoryPollingHistory, Role, SkipReason,
+    Tag, TagRule, TriggerStatus, WebhookDelivery, models::SystemSetting,
+};
 use anyhow::Result;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde_json::Value;
 use sqlx::PgPool;
 use std::collections::HashMap;
 
@@ -42,11 +48,20 @@ impl Queries {
         repo_id: i32,
         is_active: Option<bool>,
         poll_interval_seconds: Option<i32>,
+        webhook_secret: Option<&str>,
     ) -> Result<Repository> {
+        // webhook_secret: None이면 유지, 빈 문자열이면 해제
         let result = sqlx::query!(
-            "UPDATE repositories SET is_active = $1, poll_interval_seconds = $2 WHERE id = $3",
+            "UPDATE repositories
+             SET is_active = COALESCE($1, is_active),
+                 poll_interval_seconds = COALESCE($2, poll_interval_seconds),
+                 webhook_secret = CASE WHEN $3::VARCHAR IS NULL THEN webhook_secret
+                                       ELSE NULLIF($3, '') END,
+                 updated_at = NOW()
+             WHERE id = $4",
             is_active,
             poll_interval_seconds,
+            webhook_secret,
             repo_id
         )
         .execute(pool)
@@ -56,13 +71,9 @@ impl Queries {
             return Err(anyhow::anyhow!("Failed to update repository"));
         }
 
-        let repository = sqlx::query_as!(
-            Repository,
-            "SELECT * FROM repositories WHERE id = $1",
-            repo_id
-        )
-        .fetch_one(pool)
-        .await?;
+        let repository = sqlx::query_as!(Repository, "SELECT * FROM repositories WHERE id = $1", repo_id)
+            .fetch_one(pool)
+            .await?;
 
         Ok(repository)
     }
@@ -93,6 +104,22 @@ impl Queries {
         Ok(repository)
     }
 
+    pub async fn get_repository_by_name(
+        pool: &PgPool,
+        owner: &str,
+        name: &str,
+    ) -> Result<Option<Repository>> {
+        let repository = sqlx::query_as!(
+            Repository,
+            "SELECT * FROM repositories WHERE owner = $1 AND name = $2",
+            owner,
+            name
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(repository)
+    }
+
     pub async fn get_system_settings(pool: &PgPool) -> Result<HashMap<String, String>> {
         let system_settings = sqlx::query_as!(SystemSetting, "SELECT * FROM system_settings")
             .fetch_all(pool)
@@ -102,4 +129,1394 @@ impl Queries {
             .map(|s| (s.key, s.value))
             .collect())
     }
+
+    /// 폴링 실행 기록 (polled_at은 DB 현재 시각)
+    pub async fn create_polling_history(
+        pool: &PgPool,
+        repo_id: i32,
+    ) -> Result<RepositoryPollingHistory> {
+        let history = sqlx::query_as!(
+            RepositoryPollingHistory,
+            "INSERT INTO repositories_polling_history (repository_id, polled_at) VALUES ($1, NOW()) RETURNING *",
+            repo_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(history)
+    }
+
+    /// 브랜치가 없으면 생성하고, 있으면 기존 브랜치 반환
+    ///
+    /// 기존 브랜치의 head_sha는 갱신하지 않음 (브랜치 동기화에서 관리)
+    pub async fn ensure_branch(
+        pool: &PgPool,
+        repo_id: i32,
+        name: &str,
+        head_sha: &str,
+    ) -> Result<Branch> {
+        let inserted = sqlx::query_as!(
+            Branch,
+            "INSERT INTO branches (repository_id, name, head_sha) VALUES ($1, $2, $3)
+             ON CONFLICT (repository_id, name) DO NOTHING
+             RETURNING *",
+            repo_id,
+            name,
+            head_sha
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        if let Some(branch) = inserted {
+            return Ok(branch);
+        }
+
+        let branch = sqlx::query_as!(
+            Branch,
+            "SELECT * FROM branches WHERE repository_id = $1 AND name = $2",
+            repo_id,
+            name
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(branch)
+    }
+
+    pub async fn get_branches(pool: &PgPool, repo_id: i32) -> Result<Vec<Branch>> {
+        let branches = sqlx::query_as!(
+            Branch,
+            "SELECT * FROM branches WHERE repository_id = $1 ORDER BY name",
+            repo_id
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(branches)
+    }
+
+    pub async fn get_tags(pool: &PgPool, repo_id: i32) -> Result<Vec<Tag>> {
+        let tags = sqlx::query_as!(
+            Tag,
+            "SELECT * FROM tags WHERE repository_id = $1 ORDER BY name",
+            repo_id
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(tags)
+    }
+
+    pub async fn get_branch(pool: &PgPool, branch_id: i32) -> Result<Branch> {
+        let branch = sqlx::query_as!(Branch, "SELECT * FROM branches WHERE id = $1", branch_id)
+            .fetch_one(pool)
+            .await?;
+        Ok(branch)
+    }
+
+    /// 브랜치 head 갱신 (없으면 생성)
+    ///
+    /// # Returns
+    ///
+    /// (갱신된 브랜치, 새로 생성되었는지 여부)
+    pub async fn upsert_branch(
+        pool: &PgPool,
+        repo_id: i32,
+        name: &str,
+        head_sha: &str,
+    ) -> Result<(Branch, bool)> {
+        let row = sqlx::query!(
+            r#"INSERT INTO branches (repository_id, name, head_sha) VALUES ($1, $2, $3)
+               ON CONFLICT (repository_id, name)
+               DO UPDATE SET head_sha = EXCLUDED.head_sha, updated_at = NOW()
+               RETURNING id, repository_id, name, head_sha, created_at, updated_at,
+                         (xmax = 0) AS "inserted!""#,
+            repo_id,
+            name,
+            head_sha
+        )
+        .fetch_one(pool)
+        .await?;
+
+        let branch = Branch {
+            id: row.id,
+            repository_id: row.repository_id,
+            name: row.name,
+            head_sha: row.head_sha,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        };
+        Ok((branch, row.inserted))
+    }
+
+    /// 브랜치 삭제 (PR이 참조 중인 브랜치는 유지)
+    ///
+    /// # Returns
+    ///
+    /// 삭제되었으면 true
+    pub async fn delete_branch(pool: &PgPool, repo_id: i32, name: &str) -> Result<bool> {
+        let result = sqlx::query!(
+            "DELETE FROM branches b
+             WHERE b.repository_id = $1 AND b.name = $2
+               AND NOT EXISTS (
+                   SELECT 1 FROM pull_requests pr
+                   WHERE pr.source_branch_id = b.id OR pr.target_branch_id = b.id
+               )",
+            repo_id,
+            name
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 태그 커밋 갱신 (없으면 생성)
+    ///
+    /// # Returns
+    ///
+    /// (갱신된 태그, 새로 생성되었는지 여부)
+    pub async fn upsert_tag(
+        pool: &PgPool,
+        repo_id: i32,
+        name: &str,
+        commit_sha: &str,
+    ) -> Result<(Tag, bool)> {
+        let row = sqlx::query!(
+            r#"INSERT INTO tags (repository_id, name, commit_sha) VALUES ($1, $2, $3)
+               ON CONFLICT (repository_id, name) DO UPDATE SET commit_sha = EXCLUDED.commit_sha
+               RETURNING id, repository_id, name, commit_sha, created_at,
+                         (xmax = 0) AS "inserted!""#,
+            repo_id,
+            name,
+            commit_sha
+        )
+        .fetch_one(pool)
+        .await?;
+
+        let tag = Tag {
+            id: row.id,
+            repository_id: row.repository_id,
+            name: row.name,
+            commit_sha: row.commit_sha,
+            created_at: row.created_at,
+        };
+        Ok((tag, row.inserted))
+    }
+
+    pub async fn delete_tag(pool: &PgPool, repo_id: i32, name: &str) -> Result<bool> {
+        let result = sqlx::query!(
+            "DELETE FROM tags WHERE repository_id = $1 AND name = $2",
+            repo_id,
+            name
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn get_pull_request_by_number(
+        pool: &PgPool,
+        repo_id: i32,
+        pr_number: i32,
+    ) -> Result<Option<PullRequest>> {
+        let pull_request = sqlx::query_as!(
+            PullRequest,
+            "SELECT * FROM pull_requests WHERE repository_id = $1 AND pr_number = $2",
+            repo_id,
+            pr_number
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(pull_request)
+    }
+
+    pub async fn upsert_pull_request(
+        pool: &PgPool,
+        pr: &PullRequestUpsert<'_>,
+    ) -> Result<PullRequest> {
+        let pull_request = sqlx::query_as!(
+            PullRequest,
+            "INSERT INTO pull_requests
+                (repository_id, pr_number, title, author, source_branch_id, target_branch_id,
+                 head_sha, status, labels, last_polled_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, NOW())
+             ON CONFLICT (repository_id, pr_number) DO UPDATE SET
+                title = EXCLUDED.title,
+                author = EXCLUDED.author,
+                source_branch_id = EXCLUDED.source_branch_id,
+                target_branch_id = EXCLUDED.target_branch_id,
+                head_sha = EXCLUDED.head_sha,
+                status = EXCLUDED.status,
+                labels = EXCLUDED.labels,
+                last_polled_at = NOW(),
+                updated_at = NOW()
+             RETURNING *",
+            pr.repository_id,
+            pr.pr_number,
+            pr.title,
+            pr.author,
+            pr.source_branch_id,
+            pr.target_branch_id,
+            pr.head_sha,
+            pr.status,
+            pr.labels
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(pull_request)
+    }
+
+    /// 저장소의 열린 (`open`, `draft`, `reopened`) PR 목록
+    pub async fn get_open_pull_requests(pool: &PgPool, repo_id: i32) -> Result<Vec<PullRequest>> {
+        let statuses: Vec<String> = [PrStatus::Open, PrStatus::Draft, PrStatus::Reopened]
+            .iter()
+            .map(|status| status.as_str().to_string())
+            .collect();
+        let pull_requests = sqlx::query_as!(
+            PullRequest,
+            "SELECT * FROM pull_requests
+             WHERE repository_id = $1 AND status = ANY($2)
+             ORDER BY pr_number",
+            repo_id,
+            &statuses
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(pull_requests)
+    }
+
+    /// PR 상태 전이 기록
+    pub async fn create_pull_request_transition(
+        pool: &PgPool,
+        pr_id: i32,
+        from_status: Option<&str>,
+        to_status: &str,
+        head_sha: &str,
+    ) -> Result<PullRequestTransition> {
+        let transition = sqlx::query_as!(
+            PullRequestTransition,
+            "INSERT INTO pull_request_transitions (pull_request_id, from_status, to_status, head_sha)
+             VALUES ($1, $2, $3, $4)
+             RETURNING *",
+            pr_id,
+            from_status,
+            to_status,
+            head_sha
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(transition)
+    }
+
+    /// PR 상태 전이 이력 (오래된 순)
+    pub async fn get_pull_request_transitions(
+        pool: &PgPool,
+        pr_id: i32,
+    ) -> Result<Vec<PullRequestTransition>> {
+        let transitions = sqlx::query_as!(
+            PullRequestTransition,
+            "SELECT * FROM pull_request_transitions
+             WHERE pull_request_id = $1
+             ORDER BY changed_at, id",
+            pr_id
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(transitions)
+    }
+
+    /// 폴링으로 코멘트를 조회한 시각 기록
+    pub async fn set_comments_polled_at(
+        pool: &PgPool,
+        repo_id: i32,
+        polled_at: DateTime<Utc>,
+    ) -> Result<()> {
+        sqlx::query!(
+            "UPDATE repositories SET comments_polled_at = $1 WHERE id = $2",
+            polled_at,
+            repo_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 브랜치/태그 첫 동기화 완료 기록 (이후 동기화부터 새 브랜치/태그를 빌드)
+    pub async fn set_refs_baselined(pool: &PgPool, repo_id: i32) -> Result<()> {
+        sqlx::query!(
+            "UPDATE repositories SET refs_baselined_at = NOW()
+             WHERE id = $1 AND refs_baselined_at IS NULL",
+            repo_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 코멘트 명령 처리 기록 생성
+    ///
+    /// # Returns
+    ///
+    /// 이미 처리한 코멘트면 None (웹훅과 폴링 중복 방지)
+    pub async fn create_pull_request_command(
+        pool: &PgPool,
+        pr_id: i32,
+        comment_id: i64,
+        author: &str,
+        command: &str,
+        argument: Option<&str>,
+    ) -> Result<Option<PullRequestCommand>> {
+        let command = sqlx::query_as!(
+            PullRequestCommand,
+            "INSERT INTO pull_request_commands
+                (pull_request_id, comment_id, author, command, argument)
+             VALUES ($1, $2, $3, $4, $5)
+             ON CONFLICT (comment_id) DO NOTHING
+             RETURNING *",
+            pr_id,
+            comment_id,
+            author,
+            command,
+            argument
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(command)
+    }
+
+    /// 코멘트 명령 처리 결과 기록
+    pub async fn complete_pull_request_command(
+        pool: &PgPool,
+        command_id: i32,
+        result: CommandResult,
+        message: &str,
+    ) -> Result<PullRequestCommand> {
+        let command = sqlx::query_as!(
+            PullRequestCommand,
+            "UPDATE pull_request_commands
+             SET result = $1, message = $2, updated_at = NOW()
+             WHERE id = $3
+             RETURNING *",
+            result.as_str(),
+            message,
+            command_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(command)
+    }
+
+    /// 저장소의 Jenkins 매핑 목록 (등록순)
+    pub async fn get_jenkins_mappings(pool: &PgPool, repo_id: i32) -> Result<Vec<JenkinsMapping>> {
+        let mappings = sqlx::query_as!(
+            JenkinsMapping,
+            "SELECT * FROM jenkins_mappings WHERE repository_id = $1 ORDER BY id",
+            repo_id
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(mappings)
+    }
+
+    pub async fn get_jenkins_mapping(
+        pool: &PgPool,
+        mapping_id: i32,
+    ) -> Result<Option<JenkinsMapping>> {
+        let mapping = sqlx::query_as!(
+            JenkinsMapping,
+            "SELECT * FROM jenkins_mappings WHERE id = $1",
+            mapping_id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(mapping)
+    }
+
+    /// 저장소에 Jenkins 매핑 추가
+    ///
+    /// 같은 커밋 상태 context를 쓰는 매핑이 이미 있으면 None 반환
+    pub async fn create_jenkins_mapping(
+        pool: &PgPool,
+        repo_id: i32,
+        input: &JenkinsMappingInput<'_>,
+    ) -> Result<Option<JenkinsMapping>> {
+        let mapping = sqlx::query_as!(
+            JenkinsMapping,
+            "INSERT INTO jenkins_mappings
+                (repository_id, jenkins_job_name, jenkins_url, auto_trigger, status_context,
+                 target_branch_patterns, source_branch_patterns, path_patterns, required_labels,
+                 parameters, debounce_seconds, skip_draft, skip_ci_commits, cancel_previous,
+                 excluded_labels, excluded_path_patterns)
+             VALUES ($1, $2, $3, $4, COALESCE($5, 'pr-bridge/jenkins'),
+                     COALESCE($6::TEXT[], '{}'), COALESCE($7::TEXT[], '{}'),
+                     COALESCE($8::TEXT[], '{}'), COALESCE($9::TEXT[], '{}'),
+                     COALESCE($10::JSONB, '{}'), COALESCE($11, 0), COALESCE($12, FALSE),
+                     COALESCE($13, FALSE), COALESCE($14, FALSE), COALESCE($15::TEXT[], '{}'),
+                     COALESCE($16::TEXT[], '{}'))
+             ON CONFLICT (repository_id, status_context) DO NOTHING
+             RETURNING *",
+            repo_id,
+            input.jenkins_job_name,
+            input.jenkins_url,
+            input.auto_trigger,
+            input.status_context,
+            input.target_branch_patterns,
+            input.source_branch_patterns,
+            input.path_patterns,
+            input.required_labels,
+            input.parameters,
+            input.debounce_seconds,
+            input.skip_draft,
+            input.skip_ci_commits,
+            input.cancel_previous,
+            input.excluded_labels,
+            input.excluded_path_patterns
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(mapping)
+    }
+
+    /// Jenkins 매핑 수정
+    ///
+    /// 매핑이 없거나 같은 저장소의 다른 매핑이 같은 커밋 상태 context를 쓰면 None 반환
+    pub async fn update_jenkins_mapping(
+        pool: &PgPool,
+        mapping_id: i32,
+        input: &JenkinsMappingInput<'_>,
+    ) -> Result<Option<JenkinsMapping>> {
+        let mapping = sqlx::query_as!(
+            JenkinsMapping,
+            "UPDATE jenkins_mappings m SET
+                jenkins_job_name = $2,
+                jenkins_url = $3,
+                auto_trigger = $4,
+                status_context = COALESCE($5, m.status_context),
+                target_branch_patterns = COALESCE($6::TEXT[], m.target_branch_patterns),
+                source_branch_patterns = COALESCE($7::TEXT[], m.source_branch_patterns),
+                path_patterns = COALESCE($8::TEXT[], m.path_patterns),
+                required_labels = COALESCE($9::TEXT[], m.required_labels),
+                parameters = COALESCE($10::JSONB, m.parameters),
+                debounce_seconds = COALESCE($11, m.debounce_seconds),
+                skip_draft = COALESCE($12, m.skip_draft),
+                skip_ci_commits = COALESCE($13, m.skip_ci_commits),
+                cancel_previous = COALESCE($14, m.cancel_previous),
+                excluded_labels = COALESCE($15::TEXT[], m.excluded_labels),
+                excluded_path_patterns = COALESCE($16::TEXT[], m.excluded_path_patterns),
+                updated_at = NOW()
+             WHERE m.id = $1
+               AND NOT EXISTS (
+                   SELECT 1 FROM jenkins_mappings other
+                   WHERE other.repository_id = m.repository_id
+                     AND other.id <> m.id
+                     AND other.status_context = COALESCE($5, m.status_context))
+             RETURNING *",
+            mapping_id,
+            input.jenkins_job_name,
+            input.jenkins_url,
+            input.auto_trigger,
+            input.status_context,
+            input.target_branch_patterns,
+            input.source_branch_patterns,
+            input.path_patterns,
+            input.required_labels,
+            input.parameters,
+            input.debounce_seconds,
+            input.skip_draft,
+            input.skip_ci_commits,
+            input.cancel_previous,
+            input.excluded_labels,
+            input.excluded_path_patterns
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(mapping)
+    }
+
+    pub async fn delete_jenkins_mapping(
+        pool: &PgPool,
+        mapping_id: i32,
+    ) -> Result<Option<JenkinsMapping>> {
+        let mapping = sqlx::query_as!(
+            JenkinsMapping,
+            "DELETE FROM jenkins_mappings WHERE id = $1 RETURNING *",
+            mapping_id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(mapping)
+    }
+
+    /// 빌드 트리거 이력 생성 (1번 시도)
+    ///
+    /// 같은 PR의 같은 커밋이 같은 매핑으로 이미 기록되어 있으면 None 반환 (중복 트리거 방지)
+    pub async fn create_build_trigger(
+        pool: &PgPool,
+        pr_id: i32,
+        mapping_id: i32,
+        commit_sha: &str,
+        message: Option<&str>,
+    ) -> Result<Option<BuildTrigger>> {
+        let trigger = sqlx::query_as!(
+            BuildTrigger,
+            "INSERT INTO build_triggers
+                (pull_request_id, jenkins_mapping_id, commit_sha, trigger_status, trigger_message)
+             VALUES ($1, $2, $3, $4, $5)
+             ON CONFLICT (pull_request_id, jenkins_mapping_id, commit_sha, attempt) DO NOTHING
+             RETURNING *",
+            pr_id,
+            mapping_id,
+            commit_sha,
+            TriggerStatus::Pending.as_str(),
+            message
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(trigger)
+    }
+
+    /// 브랜치 빌드 트리거 이력 생성 (1번 시도)
+    ///
+    /// 같은 브랜치의 같은 커밋이 같은 매핑으로 이미 기록되어 있으면 None 반환 (중복 트리거 방지)
+    pub async fn create_branch_build_trigger(
+        pool: &PgPool,
+        branch_id: i32,
+        mapping_id: i32,
+        commit_sha: &str,
+        message: Option<&str>,
+    ) -> Result<Option<BuildTrigger>> {
+        let trigger = sqlx::query_as!(
+            BuildTrigger,
+            "INSERT INTO build_triggers
+                (branch_id, jenkins_mapping_id, commit_sha, trigger_status, trigger_message)
+             VALUES ($1, $2, $3, $4, $5)
+             ON CONFLICT (branch_id, jenkins_mapping_id, commit_sha, attempt) DO NOTHING
+             RETURNING *",
+            branch_id,
+            mapping_id,
+            commit_sha,
+            TriggerStatus::Pending.as_str(),
+            message
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(trigger)
+    }
+
+    /// 태그 빌드 트리거 이력 생성 (1번 시도)
+    ///
+    /// 같은 태그의 같은 커밋이 이미 기록되어 있으면 None 반환 (중복 트리거 방지)
+    pub async fn create_tag_build_trigger(
+        pool: &PgPool,
+        tag_id: i32,
+        mapping_id: i32,
+        commit_sha: &str,
+        jenkins_job_name: &str,
+        message: Option<&str>,
+    ) -> Result<Option<BuildTrigger>> {
+        let trigger = sqlx::query_as!(
+            BuildTrigger,
+            "INSERT INTO build_triggers
+                (tag_id, jenkins_mapping_id, commit_sha, jenkins_job_name, trigger_status,
+                 trigger_message)
+             VALUES ($1, $2, $3, $4, $5, $6)
+             ON CONFLICT (tag_id, commit_sha, attempt) DO NOTHING
+             RETURNING *",
+            tag_id,
+            mapping_id,
+            commit_sha,
+            jenkins_job_name,
+            TriggerStatus::Pending.as_str(),
+            message
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(trigger)
+    }
+
+    /// 같은 PR/매핑/커밋의 다음 시도 번호로 빌드 트리거 이력 생성 (강제 재빌드)
+    pub async fn create_retry_build_trigger(
+        pool: &PgPool,
+        pr_id: i32,
+        mapping_id: i32,
+        commit_sha: &str,
+        message: Option<&str>,
+    ) -> Result<BuildTrigger> {
+        let trigger = sqlx::query_as!(
+            BuildTrigger,
+            "INSERT INTO build_triggers
+                (pull_request_id, jenkins_mapping_id, commit_sha, trigger_status, trigger_message,
+                 attempt)
+             SELECT $1, $2, $3::VARCHAR, $4, $5, COALESCE(MAX(attempt), 0) + 1
+             FROM build_triggers
+             WHERE pull_request_id = $1 AND jenkins_mapping_id = $2 AND commit_sha = $3
+             RETURNING *",
+            pr_id,
+            mapping_id,
+            commit_sha,
+            TriggerStatus::Pending.as_str(),
+            message
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(trigger)
+    }
+
+    /// 같은 PR/매핑/커밋의 가장 최근 시도
+    pub async fn get_latest_build_trigger(
+        pool: &PgPool,
+        pr_id: i32,
+        mapping_id: i32,
+        commit_sha: &str,
+    ) -> Result<Option<BuildTrigger>> {
+        let trigger = sqlx::query_as!(
+            BuildTrigger,
+            "SELECT * FROM build_triggers
+             WHERE pull_request_id = $1 AND jenkins_mapping_id = $2 AND commit_sha = $3
+             ORDER BY attempt DESC
+             LIMIT 1",
+            pr_id,
+            mapping_id,
+            commit_sha
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(trigger)
+    }
+
+    pub async fn update_build_trigger_status(
+        pool: &PgPool,
+        trigger_id: i32,
+        status: TriggerStatus,
+        message: Option<&str>,
+    ) -> Result<BuildTrigger> {
+        let trigger = sqlx::query_as!(
+            BuildTrigger,
+            "UPDATE build_triggers SET trigger_status = $1, trigger_message = $2
+             WHERE id = $3
+             RETURNING *",
+            status.as_str(),
+            message,
+            trigger_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(trigger)
+    }
+
+    /// 자동 빌드 조건에 따라 건너뛴 트리거를 완료 처리
+    pub async fn skip_build_trigger(
+        pool: &PgPool,
+        trigger_id: i32,
+        reason: SkipReason,
+        message: &str,
+    ) -> Result<BuildTrigger> {
+        let trigger = sqlx::query_as!(
+            BuildTrigger,
+            "UPDATE build_triggers
+             SET trigger_status = $1, skip_reason = $2, trigger_message = $3,
+                 completed_at = NOW()
+             WHERE id = $4
+             RETURNING *",
+            TriggerStatus::Skipped.as_str(),
+            reason.as_str(),
+            message,
+            trigger_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(trigger)
+    }
+
+    /// 트리거 메시지만 변경 (상태 유지)
+    pub async fn set_build_trigger_message(
+        pool: &PgPool,
+        trigger_id: i32,
+        message: &str,
+    ) -> Result<BuildTrigger> {
+        let trigger = sqlx::query_as!(
+            BuildTrigger,
+            "UPDATE build_triggers SET trigger_message = $1 WHERE id = $2 RETURNING *",
+            message,
+            trigger_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(trigger)
+    }
+
+    /// 매핑의 경로 조건에 일치한 변경 파일 기록
+    pub async fn set_build_trigger_matched_paths(
+        pool: &PgPool,
+        trigger_id: i32,
+        matched_paths: &[String],
+    ) -> Result<BuildTrigger> {
+        let trigger = sqlx::query_as!(
+            BuildTrigger,
+            "UPDATE build_triggers SET matched_paths = $1 WHERE id = $2 RETURNING *",
+            matched_paths,
+            trigger_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(trigger)
+    }
+
+    /// 같은 PR/매핑에서 큐 대기/진행 중인 빌드 트리거 목록
+    pub async fn get_in_flight_pull_request_triggers(
+        pool: &PgPool,
+        pr_id: i32,
+        mapping_id: i32,
+    ) -> Result<Vec<BuildTrigger>> {
+        let statuses: Vec<String> = TriggerStatus::in_flight()
+            .iter()
+            .map(|status| status.as_str().to_string())
+            .collect();
+        let triggers = sqlx::query_as!(
+            BuildTrigger,
+            "SELECT * FROM build_triggers
+             WHERE pull_request_id = $1 AND jenkins_mapping_id = $2
+               AND completed_at IS NULL AND trigger_status = ANY($3)
+             ORDER BY triggered_at",
+            pr_id,
+            mapping_id,
+            &statuses
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(triggers)
+    }
+
+    pub async fn set_build_trigger_queued(
+        pool: &PgPool,
+        trigger_id: i32,
+        queue_url: &str,
+    ) -> Result<BuildTrigger> {
+        let trigger = sqlx::query_as!(
+            BuildTrigger,
+            "UPDATE build_triggers SET trigger_status = $1, jenkins_queue_url = $2
+             WHERE id = $3
+             RETURNING *",
+            TriggerStatus::Queued.as_str(),
+            queue_url,
+            trigger_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(trigger)
+    }
+
+    pub async fn set_build_trigger_started(
+        pool: &PgPool,
+        trigger_id: i32,
+        build_number: i32,
+        build_url: &str,
+    ) -> Result<BuildTrigger> {
+        let trigger = sqlx::query_as!(
+            BuildTrigger,
+            "UPDATE build_triggers
+             SET trigger_status = $1, jenkins_build_number = $2, jenkins_build_url = $3
+             WHERE id = $4
+             RETURNING *",
+            TriggerStatus::Started.as_str(),
+            build_number,
+            build_url,
+            trigger_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(trigger)
+    }
+
+    pub async fn get_pull_request(pool: &PgPool, pr_id: i32) -> Result<PullRequest> {
+        let pull_request = sqlx::query_as!(
+            PullRequest,
+            "SELECT * FROM pull_requests WHERE id = $1",
+            pr_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(pull_request)
+    }
+
+    /// 완료되지 않은 (큐 대기/진행 중) 빌드 트리거 목록
+    pub async fn get_in_flight_build_triggers(pool: &PgPool) -> Result<Vec<BuildTrigger>> {
+        let statuses: Vec<String> = TriggerStatus::in_flight()
+            .iter()
+            .map(|status| status.as_str().to_string())
+            .collect();
+        let triggers = sqlx::query_as!(
+            BuildTrigger,
+            "SELECT * FROM build_triggers
+             WHERE completed_at IS NULL AND trigger_status = ANY($1)
+             ORDER BY triggered_at",
+            &statuses
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(triggers)
+    }
+
+    /// 빌드 완료 결과 기록
+    pub async fn complete_build_trigger(
+        pool: &PgPool,
+        trigger_id: i32,
+        status: TriggerStatus,
+        duration_ms: i64,
+    ) -> Result<BuildTrigger> {
+        let trigger = sqlx::query_as!(
+            BuildTrigger,
+            "UPDATE build_triggers
+             SET trigger_status = $1, build_duration_ms = $2, completed_at = NOW()
+             WHERE id = $3
+             RETURNING *",
+            status.as_str(),
+            duration_ms,
+            trigger_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(trigger)
+    }
+
+    /// 빌드 결과를 확인할 수 없는 트리거를 사유와 함께 완료 처리
+    pub async fn close_build_trigger(
+        pool: &PgPool,
+        trigger_id: i32,
+        status: TriggerStatus,
+        message: &str,
+    ) -> Result<BuildTrigger> {
+        let trigger = sqlx::query_as!(
+            BuildTrigger,
+            "UPDATE build_triggers
+             SET trigger_status = $1, trigger_message = $2, completed_at = NOW()
+             WHERE id = $3
+             RETURNING *",
+            status.as_str(),
+            message,
+            trigger_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(trigger)
+    }
+
+    /// 트리거가 속한 저장소 ID (PR/브랜치/태그를 통해 조회)
+    pub async fn get_build_trigger_repository_id(
+        pool: &PgPool,
+        trigger: &BuildTrigger,
+    ) -> Result<Option<i32>> {
+        let repo_id = sqlx::query_scalar!(
+            "SELECT COALESCE(
+                 (SELECT repository_id FROM pull_requests WHERE id = $1),
+                 (SELECT repository_id FROM branches WHERE id = $2),
+                 (SELECT repository_id FROM tags WHERE id = $3)
+             )",
+            trigger.pull_request_id,
+            trigger.branch_id,
+            trigger.tag_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(repo_id)
+    }
+
+    /// 기존 시스템 설정 값 변경
+    ///
+    /// # Returns
+    ///
+    /// 존재하지 않는 key면 None
+    pub async fn update_system_setting(
+        pool: &PgPool,
+        key: &str,
+        value: &str,
+    ) -> Result<Option<SystemSetting>> {
+        let setting = sqlx::query_as!(
+            SystemSetting,
+            "UPDATE system_settings SET value = $1, updated_at = NOW() WHERE key = $2 RETURNING *",
+            value,
+            key
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(setting)
+    }
+
+    pub async fn get_system_setting(pool: &PgPool, key: &str) -> Result<Option<SystemSetting>> {
+        let setting = sqlx::query_as!(
+            SystemSetting,
+            "SELECT * FROM system_settings WHERE key = $1",
+            key
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(setting)
+    }
+
+    /// 시스템 설정 전체 목록 (설명 포함)
+    pub async fn get_system_setting_list(pool: &PgPool) -> Result<Vec<SystemSetting>> {
+        let settings = sqlx::query_as!(SystemSetting, "SELECT * FROM system_settings ORDER BY key")
+            .fetch_all(pool)
+            .await?;
+        Ok(settings)
+    }
+
+    /// 저장소의 PR 목록 (status 지정 시 해당 상태만)
+    pub async fn get_pull_requests(
+        pool: &PgPool,
+        repo_id: i32,
+        status: Option<&str>,
+    ) -> Result<Vec<PullRequest>> {
+        let pull_requests = sqlx::query_as!(
+            PullRequest,
+            "SELECT * FROM pull_requests
+             WHERE repository_id = $1 AND ($2::VARCHAR IS NULL OR status = $2)
+             ORDER BY pr_number DESC",
+            repo_id,
+            status
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(pull_requests)
+    }
+
+    /// PR의 빌드 트리거 이력 (최신순)
+    pub async fn get_build_triggers(pool: &PgPool, pr_id: i32) -> Result<Vec<BuildTrigger>> {
+        let triggers = sqlx::query_as!(
+            BuildTrigger,
+            "SELECT * FROM build_triggers
+             WHERE pull_request_id = $1
+             ORDER BY triggered_at DESC, id DESC",
+            pr_id
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(triggers)
+    }
+
+    pub async fn get_branch_build_triggers(
+        pool: &PgPool,
+        branch_id: i32,
+    ) -> Result<Vec<BuildTrigger>> {
+        let triggers = sqlx::query_as!(
+            BuildTrigger,
+            "SELECT * FROM build_triggers WHERE branch_id = $1 ORDER BY triggered_at DESC",
+            branch_id
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(triggers)
+    }
+
+    pub async fn get_branch_watch_rules(
+        pool: &PgPool,
+        repo_id: i32,
+    ) -> Result<Vec<BranchWatchRule>> {
+        let rules = sqlx::query_as!(
+            BranchWatchRule,
+            "SELECT * FROM branch_watch_rules WHERE repository_id = $1 ORDER BY pattern",
+            repo_id
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(rules)
+    }
+
+    pub async fn get_branch_watch_rule(
+        pool: &PgPool,
+        rule_id: i32,
+    ) -> Result<Option<BranchWatchRule>> {
+        let rule = sqlx::query_as!(
+            BranchWatchRule,
+            "SELECT * FROM branch_watch_rules WHERE id = $1",
+            rule_id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(rule)
+    }
+
+    /// 브랜치 감시 규칙 추가
+    ///
+    /// 같은 패턴이 이미 있으면 None 반환
+    pub async fn create_branch_watch_rule(
+        pool: &PgPool,
+        repo_id: i32,
+        pattern: &str,
+    ) -> Result<Option<BranchWatchRule>> {
+        let rule = sqlx::query_as!(
+            BranchWatchRule,
+            "INSERT INTO branch_watch_rules (repository_id, pattern) VALUES ($1, $2)
+             ON CONFLICT (repository_id, pattern) DO NOTHING
+             RETURNING *",
+            repo_id,
+            pattern
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(rule)
+    }
+
+    pub async fn delete_branch_watch_rule(
+        pool: &PgPool,
+        rule_id: i32,
+    ) -> Result<Option<BranchWatchRule>> {
+        let rule = sqlx::query_as!(
+            BranchWatchRule,
+            "DELETE FROM branch_watch_rules WHERE id = $1 RETURNING *",
+            rule_id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(rule)
+    }
+
+    pub async fn get_tag_build_triggers(pool: &PgPool, tag_id: i32) -> Result<Vec<BuildTrigger>> {
+        let triggers = sqlx::query_as!(
+            BuildTrigger,
+            "SELECT * FROM build_triggers WHERE tag_id = $1 ORDER BY triggered_at DESC",
+            tag_id
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(triggers)
+    }
+
+    /// 저장소의 태그 규칙 (등록순, 앞선 규칙이 우선 적용)
+    pub async fn get_tag_rules(pool: &PgPool, repo_id: i32) -> Result<Vec<TagRule>> {
+        let rules = sqlx::query_as!(
+            TagRule,
+            "SELECT * FROM tag_rules WHERE repository_id = $1 ORDER BY id",
+            repo_id
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(rules)
+    }
+
+    pub async fn get_tag_rule(pool: &PgPool, rule_id: i32) -> Result<Option<TagRule>> {
+        let rule = sqlx::query_as!(TagRule, "SELECT * FROM tag_rules WHERE id = $1", rule_id)
+            .fetch_optional(pool)
+            .await?;
+        Ok(rule)
+    }
+
+    /// 태그 규칙 추가
+    ///
+    /// 같은 패턴이 이미 있으면 None 반환
+    pub async fn create_tag_rule(
+        pool: &PgPool,
+        repo_id: i32,
+        mapping_id: i32,
+        pattern: &str,
+        jenkins_job_name: &str,
+        parameters: &Value,
+    ) -> Result<Option<TagRule>> {
+        let rule = sqlx::query_as!(
+            TagRule,
+            "INSERT INTO tag_rules (repository_id, jenkins_mapping_id, pattern, jenkins_job_name, parameters)
+             VALUES ($1, $2, $3, $4, $5)
+             ON CONFLICT (repository_id, pattern) DO NOTHING
+             RETURNING *",
+            repo_id,
+            mapping_id,
+            pattern,
+            jenkins_job_name,
+            parameters
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(rule)
+    }
+
+    pub async fn delete_tag_rule(pool: &PgPool, rule_id: i32) -> Result<Option<TagRule>> {
+        let rule = sqlx::query_as!(
+            TagRule,
+            "DELETE FROM tag_rules WHERE id = $1 RETURNING *",
+            rule_id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(rule)
+    }
+
+    /// 저장소 폴링 이력 (최신순, 최대 limit개)
+    pub async fn get_polling_history(
+        pool: &PgPool,
+        repo_id: i32,
+        limit: i64,
+    ) -> Result<Vec<RepositoryPollingHistory>> {
+        let history = sqlx::query_as!(
+            RepositoryPollingHistory,
+            "SELECT * FROM repositories_polling_history
+             WHERE repository_id = $1
+             ORDER BY polled_at DESC
+             LIMIT $2",
+            repo_id,
+            limit
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(history)
+    }
+
+    /// API 토큰 생성 (해시만 저장)
+    pub async fn create_api_token(
+        pool: &PgPool,
+        name: &str,
+        token_hash: &str,
+        role: Role,
+    ) -> Result<ApiToken> {
+        let token = sqlx::query_as!(
+            ApiToken,
+            "INSERT INTO api_tokens (name, token_hash, role) VALUES ($1, $2, $3)
+             RETURNING id, name, last_used_at, revoked_at, created_at, role",
+            name,
+            token_hash,
+            role.as_str()
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(token)
+    }
+
+    /// 설정 파일의 초기 토큰 등록 (이미 등록된 해시면 무시)
+    pub async fn ensure_api_token(
+        pool: &PgPool,
+        name: &str,
+        token_hash: &str,
+        role: Role,
+    ) -> Result<()> {
+        sqlx::query!(
+            "INSERT INTO api_tokens (name, token_hash, role) VALUES ($1, $2, $3)
+             ON CONFLICT (token_hash) DO NOTHING",
+            name,
+            token_hash,
+            role.as_str()
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 해시로 폐기되지 않은 토큰 조회 및 마지막 사용 시각 갱신
+    pub async fn use_api_token(pool: &PgPool, token_hash: &str) -> Result<Option<ApiToken>> {
+        let token = sqlx::query_as!(
+            ApiToken,
+            "UPDATE api_tokens SET last_used_at = NOW()
+             WHERE token_hash = $1 AND revoked_at IS NULL
+             RETURNING id, name, last_used_at, revoked_at, created_at, role",
+            token_hash
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(token)
+    }
+
+    pub async fn get_api_tokens(pool: &PgPool) -> Result<Vec<ApiToken>> {
+        let tokens = sqlx::query_as!(
+            ApiToken,
+            "SELECT id, name, last_used_at, revoked_at, created_at, role FROM api_tokens ORDER BY id"
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(tokens)
+    }
+
+    pub async fn get_api_token(pool: &PgPool, token_id: i32) -> Result<Option<ApiToken>> {
+        let token = sqlx::query_as!(
+            ApiToken,
+            "SELECT id, name, last_used_at, revoked_at, created_at, role FROM api_tokens WHERE id = $1",
+            token_id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(token)
+    }
+
+    /// API 토큰 폐기
+    pub async fn revoke_api_token(pool: &PgPool, token_id: i32) -> Result<ApiToken> {
+        let token = sqlx::query_as!(
+            ApiToken,
+            "UPDATE api_tokens SET revoked_at = NOW()
+             WHERE id = $1 AND revoked_at IS NULL
+             RETURNING id, name, last_used_at, revoked_at, created_at, role",
+            token_id
+        )
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("API token not found or already revoked"))?;
+        Ok(token)
+    }
+
+    pub async fn create_audit_log(pool: &PgPool, entry: &AuditLogEntry<'_>) -> Result<AuditLog> {
+        let audit_log = sqlx::query_as!(
+            AuditLog,
+            "INSERT INTO audit_log
+                (actor, client_id, remote_addr, message_type, payload, before_snapshot, after_snapshot)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)
+             RETURNING *",
+            entry.actor,
+            entry.client_id,
+            entry.remote_addr,
+            entry.message_type,
+            entry.payload,
+            entry.before_snapshot,
+            entry.after_snapshot
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(audit_log)
+    }
+
+    /// 감사 로그 조회 (최신순)
+    ///
+    /// actor, 기간(since 이상, until 미만)은 지정한 경우에만 적용
+    pub async fn get_audit_log(
+        pool: &PgPool,
+        actor: Option<&str>,
+        since: Option<NaiveDateTime>,
+        until: Option<NaiveDateTime>,
+        limit: i64,
+    ) -> Result<Vec<AuditLog>> {
+        let audit_log = sqlx::query_as!(
+            AuditLog,
+            "SELECT * FROM audit_log
+             WHERE ($1::VARCHAR IS NULL OR actor = $1)
+               AND ($2::TIMESTAMP IS NULL OR created_at >= $2)
+               AND ($3::TIMESTAMP IS NULL OR created_at < $3)
+             ORDER BY created_at DESC, id DESC
+             LIMIT $4",
+            actor,
+            since,
+            until,
+            limit
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(audit_log)
+    }
+
+    pub async fn create_webhook_delivery(
+        pool: &PgPool,
+        entry: &WebhookDeliveryEntry<'_>,
+    ) -> Result<WebhookDelivery> {
+        let delivery = sqlx::query_as!(
+            WebhookDelivery,
+            "INSERT INTO webhook_deliveries
+                (delivery_guid, event, repository_id, headers, body,
+                 signature_valid, outcome, error_message, replay_of)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+             RETURNING *",
+            entry.delivery_guid,
+            entry.event,
+            entry.repository_id,
+            entry.headers,
+            entry.body,
+            entry.signature_valid,
+            entry.outcome,
+            entry.error_message,
+            entry.replay_of
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(delivery)
+    }
+
+    pub async fn get_webhook_delivery(
+        pool: &PgPool,
+        delivery_id: i32,
+    ) -> Result<Option<WebhookDelivery>> {
+        let delivery = sqlx::query_as!(
+            WebhookDelivery,
+            "SELECT * FROM webhook_deliveries WHERE id = $1",
+            delivery_id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(delivery)
+    }
+
+    /// 웹훅 전송 기록 조회 (최신순)
+    ///
+    /// 저장소, 이벤트, 처리 결과는 지정한 경우에만 적용
+    pub async fn get_webhook_deliveries(
+        pool: &PgPool,
+        repo_id: Option<i32>,
+        event: Option<&str>,
+        outcome: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>> {
+        let deliveries = sqlx::query_as!(
+            WebhookDelivery,
+            "SELECT * FROM webhook_deliveries
+             WHERE ($1::INTEGER IS NULL OR repository_id = $1)
+               AND ($2::VARCHAR IS NULL OR event = $2)
+               AND ($3::VARCHAR IS NULL OR outcome = $3)
+             ORDER BY received_at DESC, id DESC
+             LIMIT $4",
+            repo_id,
+            event,
+            outcome,
+            limit
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(deliveries)
+    }
+}
+
+/// `Queries::create_audit_log` 입력값
+pub struct AuditLogEntry<'a> {
+    pub actor: Option<&'a str>,
+    pub client_id: &'a str,
+    pub remote_addr: Option<&'a str>,
+    pub message_type: &'a str,
+    pub payload: &'a Value,
+    pub before_snapshot: Option<&'a Value>,
+    pub after_snapshot: Option<&'a Value>,
+}
+
+/// `Queries::create_webhook_delivery` 입력값
+pub struct WebhookDeliveryEntry<'a> {
+    pub delivery_guid: Option<&'a str>,
+    pub event: Option<&'a str>,
+    pub repository_id: Option<i32>,
+    pub headers: &'a Value,
+    pub body: &'a str,
+    pub signature_valid: Option<bool>,
+    pub outcome: &'a str,
+    pub error_message: Option<&'a str>,
+    pub replay_of: Option<i32>,
+}
+
+/// `Queries::upsert_pull_request` 입력값
+pub struct PullRequestUpsert<'a> {
+    pub repository_id: i32,
+    pub pr_number: i32,
+    pub title: &'a str,
+    pub author: Option<&'a str>,
+    pub source_branch_id: i32,
+    pub target_branch_id: i32,
+    pub head_sha: &'a str,
+    pub status: &'a str,
+    pub labels: &'a [String],
+}
+
+/// `Queries::create_jenkins_mapping` / `update_jenkins_mapping` 입력값
+///
+/// None인 항목은 신규 매핑은 기본값, 기존 매핑은 현재 값 유지
+pub struct JenkinsMappingInput<'a> {
+    pub jenkins_job_name: &'a str,
+    pub jenkins_url: Option<&'a str>,
+    pub auto_trigger: bool,
+    pub status_context: Option<&'a str>,
+    pub target_branch_patterns: Option<&'a [String]>,
+    pub source_branch_patterns: Option<&'a [String]>,
+    pub path_patterns: Option<&'a [String]>,
+    pub required_labels: Option<&'a [String]>,
+    pub excluded_labels: Option<&'a [String]>,
+    pub excluded_path_patterns: Option<&'a [String]>,
+    /// 빌드 파라미터 템플릿 (문자열 값만 갖는 JSON 객체)
+    pub parameters: Option<&'a Value>,
+    pub debounce_seconds: Option<i32>,
+    pub skip_draft: Option<bool>,
+    pub skip_ci_commits: Option<bool>,
+    pub cancel_previous: Option<bool>,
 }
@@ -0,0 +1,7 @@
+// 하위 모듈 선언
+mod ref_sync;
+mod remote;
+
+// 공개 API
+pub use ref_sync::*;
+pub use remote::*;
@@ -0,0 +1,163 @@
+//! 브랜치/태그 동기화
+//!
+//! 원격 ref 목록과 `branches`/`tags` 테이블을 비교하여 추가/갱신/삭제 반영
+
+use crate::{
+    db::{self, Repository},
+    git::GitRemote,
+    jenkins::BuildTriggerService,
+    ws::{event_bus::EventBus, ws_message::ServerMessageType},
+};
+use anyhow::Result;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use tracing::{error, info};
+
+/// 동기화로 변경된 행 개수
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RefSyncCounts {
+    pub added: i32,
+    pub updated: i32,
+    pub deleted: i32,
+}
+
+impl RefSyncCounts {
+    pub fn is_empty(&self) -> bool {
+        *self == RefSyncCounts::default()
+    }
+}
+
+#[derive(Clone)]
+pub struct RefSyncService {
+    pool: PgPool,
+    remote: GitRemote,
+    triggers: BuildTriggerService,
+    events: EventBus,
+}
+
+impl RefSyncService {
+    pub fn new(
+        pool: &PgPool,
+        remote: GitRemote,
+        triggers: BuildTriggerService,
+        events: EventBus,
+    ) -> Self {
+        RefSyncService {
+            pool: pool.clone(),
+            remote,
+            triggers,
+            events,
+        }
+    }
+
+    /// 저장소의 브랜치/태그 동기화 후 변경이 있으면 `BranchesSynced`/`TagsSynced` 발행
+    ///
+    /// PR이 참조하는 브랜치(포크 브랜치 포함)는 원격에 없어도 삭제하지 않음.
+    /// head가 바뀐 브랜치와 새 태그는 규칙에 따라 빌드를 트리거하며, 기준점이 없는 첫
+    /// 동기화는 기존 브랜치/태그 전체가 빌드되지 않도록 기준점 기록만 수행.
+    /// 기준점은 브랜치/태그를 모두 반영한 뒤 기록하므로 중간에 실패하면 다음 동기화에서 다시 수행
+    ///
+    /// # Returns
+    ///
+    /// (브랜치 변경 개수, 태그 변경 개수)
+    pub async fn sync_repository(
+        &self,
+        repo: &Repository,
+    ) -> Result<(RefSyncCounts, RefSyncCounts)> {
+        let remote = self.remote.list_refs(&repo.owner, &repo.name).await?;
+
+        let stored_branches: HashMap<String, String> =
+            db::Queries::get_branches(&self.pool, repo.id)
+                .await?
+                .into_iter()
+                .map(|branch| (branch.name, branch.head_sha))
+                .collect();
+        let initial_sync = repo.refs_baselined_at.is_none();
+        let mut branches = RefSyncCounts::default();
+        for (name, sha) in &remote.branches {
+            if stored_branches.get(name) == Some(sha) {
+                continue;
+            }
+            let (branch, inserted) =
+                db::Queries::upsert_branch(&self.pool, repo.id, name, sha).await?;
+            if inserted {
+                branches.added += 1;
+            } else {
+                branches.updated += 1;
+            }
+            // 트리거 실패로 나머지 ref 동기화가 중단되지 않도록 ref마다 에러 기록
+            if !initial_sync && let Err(e) = self.triggers.handle_branch_update(repo, &branch).await
+            {
+                error!(
+                    "Failed to trigger build for branch {} ({}) on {}/{}: {}",
+                    branch.name, branch.head_sha, repo.owner, repo.name, e
+                );
+            }
+        }
+        for name in stored_branches.keys() {
+            if !remote.branches.contains_key(name)
+                && db::Queries::delete_branch(&self.pool, repo.id, name).await?
+            {
+                branches.deleted += 1;
+            }
+        }
+
+        let stored_tags: HashMap<String, String> = db::Queries::get_tags(&self.pool, repo.id)
+            .await?
+            .into_iter()
+            .map(|tag| (tag.name, tag.commit_sha))
+            .collect();
+        let mut tags = RefSyncCounts::default();
+        for (name, sha) in &remote.tags {
+            if stored_tags.get(name) == Some(sha) {
+                continue;
+            }
+            let (tag, inserted) = db::Queries::upsert_tag(&self.pool, repo.id, name, sha).await?;
+            if inserted {
+                tags.added += 1;
+            } else {
+                tags.updated += 1;
+            }
+            if !initial_sync && let Err(e) = self.triggers.handle_tag_update(repo, &tag).await {
+                error!(
+                    "Failed to trigger build for tag {} ({}) on {}/{}: {}",
+                    tag.name, tag.commit_sha, repo.owner, repo.name, e
+                );
+            }
+        }
+        for name in stored_tags.keys() {
+            if !remote.tags.contains_key(name)
+                && db::Queries::delete_tag(&self.pool, repo.id, name).await?
+            {
+                tags.deleted += 1;
+            }
+        }
+
+        if initial_sync {
+            db::Queries::set_refs_baselined(&self.pool, repo.id).await?;
+        }
+
+        info!(
+            "Synced refs {}/{}: branches {:?}, tags {:?}",
+            repo.owner, repo.name, branches, tags
+        );
+        if !branches.is_empty() {
+            self.events.publish(ServerMessageType::BranchesSynced {
+                repo_id: repo.id,
+                added: branches.added,
+                updated: branches.updated,
+                deleted: branches.deleted,
+            });
+        }
+        if !tags.is_empty() {
+            self.events.publish(ServerMessageType::TagsSynced {
+                repo_id: repo.id,
+                added: tags.added,
+                updated: tags.updated,
+                deleted: tags.deleted,
+            });
+        }
+
+        Ok((branches, tags))
+    }
+}
@@ -0,0 +1,298 @@
+//! `git ls-remote`를 이용한 원격 ref 조회
+//!
+//! SSH 키 파일/SSH Agent 또는 HTTPS 토큰으로 인증하며 저장소를 clone하지 않음
+
+use crate::config::Config;
+use anyhow::{Result, anyhow};
+use reqwest::Url;
+use std::{
+    collections::HashMap,
+    fs::{DirBuilder, OpenOptions},
+    io::Write,
+    os::unix::fs::{DirBuilderExt, OpenOptionsExt},
+    path::PathBuf,
+    process::Stdio,
+    time::Duration,
+};
+use tokio::process::Command;
+use tracing::debug;
+
+/// `git ls-remote` 최대 실행 시간
+const LS_REMOTE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// SSH 키 passphrase를 전달하는 환경 변수 (askpass 스크립트에서 읽음)
+const PASSPHRASE_ENV: &str = "PR_BRIDGE_SSH_PASSPHRASE";
+
+/// HTTPS 토큰을 전달하는 환경 변수 (credential helper에서 읽음)
+const TOKEN_ENV: &str = "PR_BRIDGE_GIT_TOKEN";
+
+/// 원격 저장소의 브랜치/태그 목록 (이름 → 커밋 SHA)
+#[derive(Debug, Clone, Default)]
+pub struct RemoteRefs {
+    pub branches: HashMap<String, String>,
+    /// annotated 태그는 태그 객체가 아닌 가리키는 커밋 SHA
+    pub tags: HashMap<String, String>,
+}
+
+impl RemoteRefs {
+    /// `git ls-remote` 출력 파싱 (`<sha>\t<ref>` 형식)
+    fn parse(output: &str) -> Self {
+        let mut refs = RemoteRefs::default();
+        let mut peeled = HashMap::new();
+
+        for line in output.lines() {
+            let Some((sha, full_ref)) = line.split_once('\t') else {
+                continue;
+            };
+            if let Some(name) = full_ref.strip_prefix("refs/heads/") {
+                refs.branches.insert(name.to_string(), sha.to_string());
+            } else if let Some(name) = full_ref.strip_prefix("refs/tags/") {
+                match name.strip_suffix("^{}") {
+                    Some(name) => peeled.insert(name.to_string(), sha.to_string()),
+                    None => refs.tags.insert(name.to_string(), sha.to_string()),
+                };
+            }
+        }
+
+        refs.tags.extend(peeled);
+        refs
+    }
+}
+
+/// 원격 저장소 접근 방식
+#[derive(Debug, Clone)]
+enum Transport {
+    /// `git@host:owner/name.git`
+    Ssh {
+        key_path: Option<PathBuf>,
+        /// passphrase가 있는 키 파일용 askpass 스크립트와 passphrase
+        askpass: Option<(PathBuf, String)>,
+        use_agent: bool,
+    },
+    /// `https://host/owner/name.git`
+    Https { token: Option<String> },
+}
+
+#[derive(Debug, Clone)]
+pub struct GitRemote {
+    host: String,
+    transport: Transport,
+}
+
+impl GitRemote {
+    /// 설정에 따라 접근 방식 결정
+    ///
+    /// SSH 키 파일이나 SSH Agent가 설정되어 있으면 SSH, 아니면 GitHub 토큰으로 HTTPS 사용
+    pub fn new(config: &Config) -> Result<Self> {
+        let host = git_host(&config.github_api_url)?;
+
+        let transport = if config.git_ssh_key_path.is_some() || config.git_use_ssh_agent {
+            let askpass = match (&config.git_ssh_key_path, &config.git_ssh_key_passphrase) {
+                (Some(_), Some(passphrase)) if !passphrase.is_empty() => {
+                    Some((write_askpass_script()?, passphrase.clone()))
+                }
+                _ => None,
+            };
+            Transport::Ssh {
+                key_path: config.git_ssh_key_path.clone(),
+                askpass,
+                use_agent: config.git_use_ssh_agent,
+            }
+        } else {
+            Transport::Https {
+                token: config.github_token.clone(),
+            }
+        };
+
+        Ok(GitRemote { host, transport })
+    }
+
+    /// 원격 저장소의 브랜치/태그 목록 조회
+    pub async fn list_refs(&self, owner: &str, name: &str) -> Result<RemoteRefs> {
+        let url = self.url(owner, name);
+        let mut command = Command::new("git");
+        command
+            .args(["ls-remote", "--heads", "--tags", "--", &url])
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .stdin(Stdio::null())
+            .kill_on_drop(true);
+        self.configure_auth(&mut command);
+
+        debug!("git ls-remote {}", url);
+        let output = tokio::time::timeout(LS_REMOTE_TIMEOUT, command.output())
+            .await
+            .map_err(|_| anyhow!("git ls-remote timed out: {}", url))??;
+        if !output.status.success() {
+            return Err(anyhow!(
+                "git ls-remote failed: {}: {}",
+                url,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        Ok(RemoteRefs::parse(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    fn url(&self, owner: &str, name: &str) -> String {
+        match self.transport {
+            Transport::Ssh { .. } => format!("git@{}:{}/{}.git", self.host, owner, name),
+            Transport::Https { .. } => format!("https://{}/{}/{}.git", self.host, owner, name),
+        }
+    }
+
+    /// 인증 정보는 프로세스 인자에 노출되지 않도록 환경 변수로 전달
+    fn configure_auth(&self, command: &mut Command) {
+        match &self.transport {
+            Transport::Ssh {
+                key_path,
+                askpass,
+                use_agent,
+            } => {
+                let mut ssh = vec![
+                    "ssh".to_string(),
+                    "-o StrictHostKeyChecking=accept-new".to_string(),
+                    "-o PreferredAuthentications=publickey".to_string(),
+                ];
+                if let Some(key_path) = key_path {
+                    ssh.push(format!("-i '{}'", key_path.display()));
+                    ssh.push("-o IdentitiesOnly=yes".to_string());
+                }
+                if !use_agent {
+                    ssh.push("-o IdentityAgent=none".to_string());
+                }
+                match askpass {
+                    Some((script, passphrase)) => {
+                        command
+                            .env("SSH_ASKPASS", script)
+                            .env("SSH_ASKPASS_REQUIRE", "force")
+                            .env(PASSPHRASE_ENV, passphrase);
+                    }
+                    None => ssh.push("-o BatchMode=yes".to_string()),
+                }
+                command.env("GIT_SSH_COMMAND", ssh.join(" "));
+            }
+            Transport::Https { token: Some(token) } => {
+                command
+                    .env("GIT_CONFIG_COUNT", "1")
+                    .env("GIT_CONFIG_KEY_0", "credential.helper")
+                    .env(
+                        "GIT_CONFIG_VALUE_0",
+                        format!(
+                            "!f() {{ echo username=x-access-token; echo \"password=${}\"; }}; f",
+                            TOKEN_ENV
+                        ),
+                    )
+                    .env(TOKEN_ENV, token);
+            }
+            Transport::Https { token: None } => {}
+        }
+    }
+}
+
+/// GitHub API 주소에서 git 호스트 추출
+///
+/// `https://api.github.com` → `github.com`, GHE `https://host/api/v3` → `host`
+fn git_host(api_url: &str) -> Result<String> {
+    let url = Url::parse(api_url)?;
+    let host = url
+        .host_str()
+        .ok_or_else(|| anyhow!("Invalid GitHub API URL: {}", api_url))?;
+    Ok(match host {
+        "api.github.com" => "github.com".to_string(),
+        host => host.to_string(),
+    })
+}
+
+/// passphrase를 환경 변수에서 읽어 출력하는 askpass 스크립트 생성
+///
+/// passphrase 자체는 파일에 기록하지 않음. 다른 사용자가 미리 만든 파일이나 심볼릭 링크를
+/// 쓰지 않도록 임시 디렉터리 아래에 이름을 예측할 수 없는 0700 디렉터리를 새로 만들고,
+/// 그 안에 실행 권한으로 새 파일을 생성
+fn write_askpass_script() -> Result<PathBuf> {
+    let dir = std::env::temp_dir().join(format!(
+        "pr-bridge-askpass-{}",
+        hex::encode(rand::random::<[u8; 16]>())
+    ));
+    DirBuilder::new().mode(0o700).create(&dir)?;
+
+    let path = dir.join("askpass.sh");
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o700)
+        .open(&path)?;
+    file.write_all(format!("#!/bin/sh\nprintf '%s\\n' \"${}\"\n", PASSPHRASE_ENV).as_bytes())?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn askpass_script_is_private_and_unique() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let first = write_askpass_script().unwrap();
+        let second = write_askpass_script().unwrap();
+        assert_ne!(first.parent(), second.parent());
+
+        for path in [&first, &second] {
+            let dir = path.parent().unwrap();
+            let dir_mode = std::fs::metadata(dir).unwrap().permissions().mode();
+            assert_eq!(dir_mode & 0o077, 0);
+            let file_mode = std::fs::metadata(path).unwrap().permissions().mode();
+            assert_eq!(file_mode & 0o077, 0);
+            assert!(
+                std::fs::read_to_string(path)
+                    .unwrap()
+                    .contains(PASSPHRASE_ENV)
+            );
+            std::fs::remove_dir_all(dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn parses_branches_and_lightweight_tags() {
+        let output = "1111\tHEAD\n\
+                      2222\trefs/heads/main\n\
+                      3333\trefs/heads/feature/login\n\
+                      4444\trefs/tags/v1.0\n";
+        let refs = RemoteRefs::parse(output);
+        assert_eq!(
+            refs.branches,
+            HashMap::from([
+                ("main".to_string(), "2222".to_string()),
+                ("feature/login".to_string(), "3333".to_string()),
+            ])
+        );
+        assert_eq!(
+            refs.tags,
+            HashMap::from([("v1.0".to_string(), "4444".to_string())])
+        );
+    }
+
+    #[test]
+    fn uses_peeled_commit_for_annotated_tags() {
+        let output = "aaaa\trefs/tags/v2.0\n\
+                      bbbb\trefs/tags/v2.0^{}\n\
+                      dddd\trefs/tags/v3.0^{}\n\
+                      cccc\trefs/tags/v3.0\n";
+        let refs = RemoteRefs::parse(output);
+        assert_eq!(refs.tags.get("v2.0").map(String::as_str), Some("bbbb"));
+        assert_eq!(refs.tags.get("v3.0").map(String::as_str), Some("dddd"));
+        assert_eq!(refs.tags.len(), 2);
+    }
+
+    #[test]
+    fn ignores_other_refs_and_malformed_lines() {
+        let output = "eeee\trefs/pull/1/head\n\
+                      not a ref line\n\
+                      \n\
+                      ffff\trefs/heads/main";
+        let refs = RemoteRefs::parse(output);
+        assert_eq!(refs.branches.len(), 1);
+        assert_eq!(refs.branches.get("main").map(String::as_str), Some("ffff"));
+        assert!(refs.tags.is_empty());
+    }
+}
@@ -0,0 +1,340 @@
+use crate::github::models::{
+    CommitStatus, GithubCommit, GithubIssueComment, GithubPermission, GithubPullRequest,
+    GithubPullRequestFile, Reaction,
+};
+use anyhow::{Result, anyhow};
+use reqwest::{
+    Method, RequestBuilder, Response, StatusCode,
+    header::{self, HeaderMap},
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::sync::Mutex;
+use tracing::debug;
+
+/// 한 페이지당 조회 개수 (GitHub 최대값)
+const PER_PAGE: u32 = 100;
+
+/// ETag 조건부 요청을 위한 응답 캐시 항목
+#[derive(Debug, Clone)]
+struct CachedPage {
+    etag: String,
+    body: Value,
+    next: Option<String>,
+}
+
+/// 단일 페이지 조회 결과
+struct Page {
+    body: Value,
+    next: Option<String>,
+    not_modified: bool,
+}
+
+/// 페이지네이션 목록 조회 결과
+#[derive(Debug, Clone)]
+pub struct Listing<T> {
+    pub items: Vec<T>,
+    /// 모든 페이지가 304 Not Modified 응답이었는지 여부
+    pub not_modified: bool,
+}
+
+/// GitHub REST API 클라이언트
+///
+/// 페이지 URL 단위로 ETag를 캐싱하여 변경이 없으면 `If-None-Match` 조건부 요청으로
+/// 레이트 리밋 소모 없이 이전 응답 재사용
+#[derive(Debug, Clone)]
+pub struct GithubClient {
+    http: reqwest::Client,
+    api_url: String,
+    token: Option<String>,
+    etag_cache: Arc<Mutex<HashMap<String, CachedPage>>>,
+}
+
+impl GithubClient {
+    /// # Arguments
+    ///
+    /// * `api_url` - API 기본 주소 (예: `https://api.github.com`, GHE는 `https://host/api/v3`)
+    /// * `token` - 인증 토큰 (없으면 비인증 요청)
+    pub fn new(api_url: &str, token: Option<String>) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .user_agent(concat!("pr-bridge/", env!("CARGO_PKG_VERSION")))
+            .timeout(Duration::from_secs(30))
+            .build()?;
+
+        Ok(GithubClient {
+            http,
+            api_url: api_url.trim_end_matches('/').to_string(),
+            token,
+            etag_cache: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+
+    /// 저장소의 열린 PR 전체 목록 조회
+    pub async fn list_pull_requests(
+        &self,
+        owner: &str,
+        name: &str,
+    ) -> Result<Listing<GithubPullRequest>> {
+        let url = format!(
+            "{}/repos/{}/{}/pulls?state=open&per_page={}",
+            self.api_url, owner, name, PER_PAGE
+        );
+        self.get_paginated(url).await
+    }
+
+    /// PR 하나 조회 (닫힌 PR 포함)
+    pub async fn get_pull_request(
+        &self,
+        owner: &str,
+        name: &str,
+        pr_number: i32,
+    ) -> Result<GithubPullRequest> {
+        let url = format!(
+            "{}/repos/{}/{}/pulls/{}",
+            self.api_url, owner, name, pr_number
+        );
+        let resp = self.request(Method::GET, &url).send().await?;
+        Ok(Self::check_status(resp).await?.json().await?)
+    }
+
+    /// PR에서 변경된 파일 경로 목록 조회 (GitHub 제한으로 최대 3000개)
+    pub async fn list_pull_request_files(
+        &self,
+        owner: &str,
+        name: &str,
+        pr_number: i32,
+    ) -> Result<Vec<String>> {
+        let url = format!(
+            "{}/repos/{}/{}/pulls/{}/files?per_page={}",
+            self.api_url, owner, name, pr_number, PER_PAGE
+        );
+        let listing: Listing<GithubPullRequestFile> = self.get_paginated(url).await?;
+        Ok(listing
+            .items
+            .into_iter()
+            .map(|file| file.filename)
+            .collect())
+    }
+
+    /// 브랜치/태그가 가리키는 커밋 SHA 조회
+    ///
+    /// # Arguments
+    ///
+    /// * `git_ref` - 브랜치 또는 태그 이름
+    pub async fn get_commit_sha(&self, owner: &str, name: &str, git_ref: &str) -> Result<String> {
+        let url = format!(
+            "{}/repos/{}/{}/commits/{}",
+            self.api_url, owner, name, git_ref
+        );
+        let resp = self
+            .request(Method::GET, &url)
+            .header(header::ACCEPT, "application/vnd.github.sha")
+            .send()
+            .await?;
+        let resp = Self::check_status(resp).await?;
+        Ok(resp.text().await?.trim().to_string())
+    }
+
+    /// 커밋 메시지 조회
+    pub async fn get_commit_message(&self, owner: &str, name: &str, sha: &str) -> Result<String> {
+        let url = format!("{}/repos/{}/{}/commits/{}", self.api_url, owner, name, sha);
+        let resp = self.request(Method::GET, &url).send().await?;
+        let commit: GithubCommit = Self::check_status(resp).await?.json().await?;
+        Ok(commit.commit.message)
+    }
+
+    /// 저장소 이슈/PR 코멘트 중 지정 시각 이후 작성/수정된 코멘트 목록 (작성순)
+    ///
+    /// 조회 시각마다 주소가 달라지므로 ETag 캐시를 사용하지 않음
+    ///
+    /// # Arguments
+    ///
+    /// * `since` - ISO 8601 시각 (예: `2024-01-01T00:00:00Z`)
+    pub async fn list_issue_comments(
+        &self,
+        owner: &str,
+        name: &str,
+        since: &str,
+    ) -> Result<Vec<GithubIssueComment>> {
+        let mut comments = Vec::new();
+        let mut next = Some(format!(
+            "{}/repos/{}/{}/issues/comments?sort=created&direction=asc&since={}&per_page={}",
+            self.api_url, owner, name, since, PER_PAGE
+        ));
+
+        while let Some(url) = next {
+            let resp = self.request(Method::GET, &url).send().await?;
+            let resp = Self::check_status(resp).await?;
+            next = next_page_url(resp.headers());
+            comments.extend(resp.json::<Vec<GithubIssueComment>>().await?);
+        }
+        Ok(comments)
+    }
+
+    /// 사용자의 저장소 권한 조회
+    pub async fn get_collaborator_permission(
+        &self,
+        owner: &str,
+        name: &str,
+        username: &str,
+    ) -> Result<GithubPermission> {
+        let url = format!(
+            "{}/repos/{}/{}/collaborators/{}/permission",
+            self.api_url, owner, name, username
+        );
+        let resp = self.request(Method::GET, &url).send().await?;
+        Ok(Self::check_status(resp).await?.json().await?)
+    }
+
+    /// 이슈/PR 코멘트에 반응 추가
+    pub async fn create_comment_reaction(
+        &self,
+        owner: &str,
+        name: &str,
+        comment_id: i64,
+        reaction: Reaction,
+    ) -> Result<()> {
+        let url = format!(
+            "{}/repos/{}/{}/issues/comments/{}/reactions",
+            self.api_url, owner, name, comment_id
+        );
+        let resp = self
+            .request(Method::POST, &url)
+            .json(&serde_json::json!({ "content": reaction }))
+            .send()
+            .await?;
+        Self::check_status(resp).await?;
+        Ok(())
+    }
+
+    /// 커밋 상태 생성
+    ///
+    /// # Arguments
+    ///
+    /// * `sha` - 상태를 표시할 커밋
+    /// * `status` - 상태, 링크, 설명, context
+    pub async fn create_commit_status(
+        &self,
+        owner: &str,
+        name: &str,
+        sha: &str,
+        status: &CommitStatus,
+    ) -> Result<()> {
+        let url = format!("{}/repos/{}/{}/statuses/{}", self.api_url, owner, name, sha);
+        let resp = self.request(Method::POST, &url).json(status).send().await?;
+        Self::check_status(resp).await?;
+        Ok(())
+    }
+
+    fn request(&self, method: Method, url: &str) -> RequestBuilder {
+        let mut req = self
+            .http
+            .request(method, url)
+            .header(header::ACCEPT, "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28");
+        if let Some(token) = &self.token {
+            req = req.bearer_auth(token);
+        }
+        req
+    }
+
+    /// `Link` 헤더의 next 링크를 따라 모든 페이지 조회
+    async fn get_paginated<T: DeserializeOwned>(&self, url: String) -> Result<Listing<T>> {
+        let mut items = Vec::new();
+        let mut not_modified = true;
+        let mut next = Some(url);
+
+        while let Some(url) = next {
+            let page = self.get_page(&url).await?;
+            not_modified &= page.not_modified;
+            items.extend(serde_json::from_value::<Vec<T>>(page.body)?);
+            next = page.next;
+        }
+
+        Ok(Listing {
+            items,
+            not_modified,
+        })
+    }
+
+    /// 단일 페이지 조회 (캐시된 ETag가 있으면 조건부 요청)
+    async fn get_page(&self, url: &str) -> Result<Page> {
+        let cached = self.etag_cache.lock().await.get(url).cloned();
+
+        let mut req = self.request(Method::GET, url);
+        if let Some(cached) = &cached {
+            req = req.header(header::IF_NONE_MATCH, &cached.etag);
+        }
+
+        let resp = req.send().await?;
+        if resp.status() == StatusCode::NOT_MODIFIED {
+            debug!("GitHub not modified: {}", url);
+            return match cached {
+                Some(cached) => Ok(Page {
+                    body: cached.body,
+                    next: cached.next,
+                    not_modified: true,
+                }),
+                None => Err(anyhow!(
+                    "GitHub returned 304 without cached response: {}",
+                    url
+                )),
+            };
+        }
+
+        let resp = Self::check_status(resp).await?;
+        let etag = resp
+            .headers()
+            .get(header::ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string());
+        let next = next_page_url(resp.headers());
+        let body: Value = resp.json().await?;
+
+        if let Some(etag) = etag {
+            self.etag_cache.lock().await.insert(
+                url.to_string(),
+                CachedPage {
+                    etag,
+                    body: body.clone(),
+                    next: next.clone(),
+                },
+            );
+        }
+
+        Ok(Page {
+            body,
+            next,
+            not_modified: false,
+        })
+    }
+
+    /// 실패 응답을 상태 코드와 본문이 포함된 에러로 변환
+    async fn check_status(resp: Response) -> Result<Response> {
+        if resp.status().is_success() {
+            return Ok(resp);
+        }
+
+        let status = resp.status();
+        let url = resp.url().to_string();
+        let body = resp.text().await.unwrap_or_default();
+        Err(anyhow!("GitHub API error: {} {} {}", status, url, body))
+    }
+}
+
+/// `Link: <url>; rel="next", <url>; rel="last"` 헤더에서 다음 페이지 URL 추출
+fn next_page_url(headers: &HeaderMap) -> Option<String> {
+    let link = headers.get(header::LINK)?.to_str().ok()?;
+    link.split(',').find_map(|part| {
+        let mut segments = part.split(';');
+        let url = segments
+            .next()?
+            .trim()
+            .trim_start_matches('<')
+            .trim_end_matches('>');
+        segments
+            .any(|s| s.trim() == "rel=\"next\"")
+            .then(|| url.to_string())
+    })
+}
@@ -0,0 +1,9 @@
+// 하위 모듈 선언
+mod client;
+mod models;
+mod poller;
+
+// 공개 API
+pub use client::*;
+pub use models::*;
+pub use poller::*;
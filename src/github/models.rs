@@ -0,0 +1,176 @@
+//! GitHub REST API 응답 모델
+//!
+//! 필요한 필드만 정의하며 나머지 필드는 무시
+
+use crate::db::PrStatus;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GithubUser {
+    pub login: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GithubLabel {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GithubRepo {
+    pub full_name: String,
+}
+
+/// PR의 head/base 브랜치 정보
+#[derive(Debug, Clone, Deserialize)]
+pub struct GithubPullRequestRef {
+    /// `owner:branch` 형식의 라벨
+    pub label: String,
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+    pub sha: String,
+    /// 포크 저장소가 삭제된 경우 null
+    pub repo: Option<GithubRepo>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GithubPullRequest {
+    pub number: i32,
+    pub title: String,
+    /// "open" 또는 "closed"
+    pub state: String,
+    pub user: Option<GithubUser>,
+    pub head: GithubPullRequestRef,
+    pub base: GithubPullRequestRef,
+    /// 머지된 시각 (머지되지 않았으면 null)
+    #[serde(default)]
+    pub merged_at: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<GithubLabel>,
+    /// 초안 PR 여부
+    #[serde(default)]
+    pub draft: bool,
+}
+
+impl GithubPullRequest {
+    /// GitHub 응답 기준 상태 (`Open`, `Draft`, `Closed`, `Merged`)
+    ///
+    /// 다시 열린 PR인지는 이전 상태와 비교해야 하므로 `PrStatus::next`로 판단
+    pub fn status(&self) -> PrStatus {
+        if self.merged_at.is_some() {
+            PrStatus::Merged
+        } else if self.state != "open" {
+            PrStatus::Closed
+        } else if self.draft {
+            PrStatus::Draft
+        } else {
+            PrStatus::Open
+        }
+    }
+
+    /// 라벨 이름 목록
+    pub fn label_names(&self) -> Vec<String> {
+        self.labels.iter().map(|label| label.name.clone()).collect()
+    }
+
+    /// 저장소 내부 브랜치 테이블에 기록할 source 브랜치 이름
+    ///
+    /// 포크에서 올라온 PR은 같은 이름의 브랜치와 충돌하지 않도록 `owner:branch` 라벨 사용
+    pub fn source_branch_name(&self, owner: &str, name: &str) -> String {
+        let full_name = format!("{}/{}", owner, name);
+        match &self.head.repo {
+            Some(repo) if repo.full_name.eq_ignore_ascii_case(&full_name) => {
+                self.head.ref_name.clone()
+            }
+            _ => self.head.label.clone(),
+        }
+    }
+}
+
+/// `GET /repos/{owner}/{repo}/commits/{ref}` 응답의 커밋 정보
+#[derive(Debug, Clone, Deserialize)]
+pub struct GithubCommitDetail {
+    pub message: String,
+}
+
+/// `GET /repos/{owner}/{repo}/commits/{ref}` 응답
+#[derive(Debug, Clone, Deserialize)]
+pub struct GithubCommit {
+    pub commit: GithubCommitDetail,
+}
+
+/// `GET /repos/{owner}/{repo}/pulls/{number}/files` 응답 항목
+#[derive(Debug, Clone, Deserialize)]
+pub struct GithubPullRequestFile {
+    pub filename: String,
+}
+
+/// 이슈/PR 코멘트 (`GET /repos/{owner}/{repo}/issues/comments` 응답 항목, 웹훅 `comment`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct GithubIssueComment {
+    pub id: i64,
+    #[serde(default)]
+    pub body: String,
+    pub user: Option<GithubUser>,
+    /// 코멘트가 달린 이슈/PR의 API 주소 (`.../issues/{number}`)
+    pub issue_url: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl GithubIssueComment {
+    /// 코멘트가 달린 이슈/PR 번호
+    pub fn issue_number(&self) -> Option<i32> {
+        self.issue_url.rsplit('/').next()?.parse().ok()
+    }
+
+    /// 작성자 로그인 (삭제된 계정이면 None)
+    pub fn author(&self) -> Option<&str> {
+        self.user.as_ref().map(|user| user.login.as_str())
+    }
+}
+
+/// `GET /repos/{owner}/{repo}/collaborators/{username}/permission` 응답
+#[derive(Debug, Clone, Deserialize)]
+pub struct GithubPermission {
+    /// "admin", "maintain", "write", "triage", "read", "none"
+    pub permission: String,
+}
+
+impl GithubPermission {
+    /// 저장소 쓰기 권한 여부
+    pub fn can_write(&self) -> bool {
+        matches!(self.permission.as_str(), "admin" | "maintain" | "write")
+    }
+}
+
+/// 코멘트 반응 (`POST .../issues/comments/{id}/reactions`의 `content`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Reaction {
+    #[serde(rename = "+1")]
+    ThumbsUp,
+    #[serde(rename = "-1")]
+    ThumbsDown,
+    #[serde(rename = "confused")]
+    Confused,
+}
+
+/// 커밋 상태 값
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommitState {
+    Pending,
+    Success,
+    Failure,
+    Error,
+}
+
+/// `POST /repos/{owner}/{repo}/statuses/{sha}` 요청 본문
+#[derive(Debug, Clone, Serialize)]
+pub struct CommitStatus {
+    pub state: CommitState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_url: Option<String>,
+    /// 최대 140자
+    pub description: String,
+    pub context: String,
+}
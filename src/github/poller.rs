@@ -0,0 +1,275 @@
+//! GitHub PR 폴링 및 DB 동기화
+
+use crate::{
+    db::{self, PrStatus, PullRequest, PullRequestUpsert, Repository},
+    github::{GithubClient, GithubPullRequest},
+    ws::{event_bus::EventBus, ws_message::ServerMessageType},
+};
+use anyhow::Result;
+use sqlx::PgPool;
+use std::collections::HashSet;
+use tracing::{debug, info, warn};
+
+/// 폴링으로 감지된 PR 변경 정보
+#[derive(Debug, Clone)]
+pub struct PullRequestChange {
+    /// 동기화 이전 상태 (새 PR이면 None)
+    pub previous: Option<PullRequest>,
+    /// 동기화 이후 상태
+    pub current: PullRequest,
+}
+
+impl PullRequestChange {
+    pub fn is_new(&self) -> bool {
+        self.previous.is_none()
+    }
+
+    /// 새 PR이거나 head 커밋이 바뀐 경우
+    pub fn head_changed(&self) -> bool {
+        match &self.previous {
+            Some(prev) => prev.head_sha != self.current.head_sha,
+            None => true,
+        }
+    }
+
+    /// 상태가 바뀐 경우 (새 PR 포함)
+    pub fn status_changed(&self) -> bool {
+        match &self.previous {
+            Some(prev) => prev.status != self.current.status,
+            None => true,
+        }
+    }
+
+    /// 이전 상태에 없던 라벨 (새 PR이면 전체)
+    pub fn added_labels(&self) -> Vec<String> {
+        let previous = self.previous.as_ref().map_or(&[][..], |prev| &prev.labels);
+        label_difference(&self.current.labels, previous)
+    }
+
+    /// 이전 상태에서 제거된 라벨
+    pub fn removed_labels(&self) -> Vec<String> {
+        let previous = self.previous.as_ref().map_or(&[][..], |prev| &prev.labels);
+        label_difference(previous, &self.current.labels)
+    }
+
+    /// 라벨이 추가/제거된 경우 (새 PR은 라벨이 있을 때)
+    pub fn labels_changed(&self) -> bool {
+        !self.added_labels().is_empty() || !self.removed_labels().is_empty()
+    }
+
+    /// 초안에서 리뷰 준비 완료로 바뀐 경우
+    pub fn ready_for_review(&self) -> bool {
+        self.previous.as_ref().is_some_and(|prev| prev.is_draft())
+            && self.current.is_open()
+            && !self.current.is_draft()
+    }
+}
+
+#[derive(Clone)]
+pub struct PullRequestPoller {
+    pool: PgPool,
+    client: GithubClient,
+    events: EventBus,
+}
+
+impl PullRequestPoller {
+    pub fn new(pool: &PgPool, client: GithubClient, events: EventBus) -> Self {
+        PullRequestPoller {
+            pool: pool.clone(),
+            client,
+            events,
+        }
+    }
+
+    /// 저장소의 열린 PR 목록을 조회하여 `pull_requests` 테이블에 반영
+    ///
+    /// 열린 상태로 기록되어 있지만 목록에서 사라진 PR은 개별 조회하여 닫힘/머지 반영.
+    /// 내용이 바뀐 PR만 변경 정보로 반환하며, 응답이 304 Not Modified이면 빈 목록 반환
+    pub async fn poll_repository(&self, repo: &Repository) -> Result<Vec<PullRequestChange>> {
+        let listing = self
+            .client
+            .list_pull_requests(&repo.owner, &repo.name)
+            .await?;
+        if listing.not_modified {
+            debug!("Pull requests not modified: {}/{}", repo.owner, repo.name);
+            return Ok(Vec::new());
+        }
+
+        let mut changes = Vec::new();
+        for gh_pr in &listing.items {
+            if let Some(change) = self.apply_pull_request(repo, gh_pr).await? {
+                changes.push(change);
+            }
+        }
+
+        let listed: HashSet<i32> = listing.items.iter().map(|gh_pr| gh_pr.number).collect();
+        for pr in db::Queries::get_open_pull_requests(&self.pool, repo.id).await? {
+            if listed.contains(&pr.pr_number) {
+                continue;
+            }
+            let gh_pr = match self
+                .client
+                .get_pull_request(&repo.owner, &repo.name, pr.pr_number)
+                .await
+            {
+                Ok(gh_pr) => gh_pr,
+                Err(e) => {
+                    warn!(
+                        "Failed to get pull request: {}/{}#{}: {}",
+                        repo.owner, repo.name, pr.pr_number, e
+                    );
+                    continue;
+                }
+            };
+            if let Some(change) = self.apply_pull_request(repo, &gh_pr).await? {
+                changes.push(change);
+            }
+        }
+
+        info!(
+            "Polled {}/{}: {} open pull requests, {} changed",
+            repo.owner,
+            repo.name,
+            listing.items.len(),
+            changes.len()
+        );
+        Ok(changes)
+    }
+
+    /// GitHub PR 하나를 DB에 반영하고 내용이 바뀐 경우 이벤트 발행
+    ///
+    /// 폴링과 웹훅이 같은 경로로 PR을 반영하도록 공유하며, 바뀐 내용이 없으면 None 반환
+    pub async fn apply_pull_request(
+        &self,
+        repo: &Repository,
+        gh_pr: &GithubPullRequest,
+    ) -> Result<Option<PullRequestChange>> {
+        let change = self.sync_pull_request(repo, gh_pr).await?;
+        if !change.head_changed() && !Self::metadata_changed(&change) {
+            return Ok(None);
+        }
+        self.publish_change(&change);
+        Ok(Some(change))
+    }
+
+    /// GitHub PR 하나를 브랜치/PR 테이블에 upsert
+    pub async fn sync_pull_request(
+        &self,
+        repo: &Repository,
+        gh_pr: &GithubPullRequest,
+    ) -> Result<PullRequestChange> {
+        let source_branch_name = gh_pr.source_branch_name(&repo.owner, &repo.name);
+        let source_branch =
+            db::Queries::ensure_branch(&self.pool, repo.id, &source_branch_name, &gh_pr.head.sha)
+                .await?;
+        let target_branch =
+            db::Queries::ensure_branch(&self.pool, repo.id, &gh_pr.base.ref_name, &gh_pr.base.sha)
+                .await?;
+
+        let previous =
+            db::Queries::get_pull_request_by_number(&self.pool, repo.id, gh_pr.number).await?;
+        let status = PrStatus::next(
+            previous
+                .as_ref()
+                .and_then(|prev| PrStatus::parse(&prev.status)),
+            gh_pr.status(),
+        );
+
+        let labels = gh_pr.label_names();
+        let current = db::Queries::upsert_pull_request(
+            &self.pool,
+            &PullRequestUpsert {
+                repository_id: repo.id,
+                pr_number: gh_pr.number,
+                title: &gh_pr.title,
+                author: gh_pr.user.as_ref().map(|user| user.login.as_str()),
+                source_branch_id: source_branch.id,
+                target_branch_id: target_branch.id,
+                head_sha: &gh_pr.head.sha,
+                status: status.as_str(),
+                labels: &labels,
+            },
+        )
+        .await?;
+
+        let change = PullRequestChange { previous, current };
+        if change.status_changed() {
+            db::Queries::create_pull_request_transition(
+                &self.pool,
+                change.current.id,
+                change.previous.as_ref().map(|prev| prev.status.as_str()),
+                &change.current.status,
+                &change.current.head_sha,
+            )
+            .await?;
+            info!(
+                "Pull request status changed: {}/{}#{} {} -> {}",
+                repo.owner,
+                repo.name,
+                change.current.pr_number,
+                change
+                    .previous
+                    .as_ref()
+                    .map_or("(new)", |prev| prev.status.as_str()),
+                change.current.status
+            );
+        }
+        Ok(change)
+    }
+
+    /// PR 변경 이벤트 발행
+    ///
+    /// 새로 열리거나 다시 열린 PR은 `PrOpened`, 닫히거나 머지된 PR은 `PrClosed`,
+    /// 그 외 (초안 전환, 커밋/제목 변경 등) `PrUpdated`
+    fn publish_change(&self, change: &PullRequestChange) {
+        let pr = &change.current;
+        let was_open = change.previous.as_ref().is_some_and(|prev| prev.is_open());
+        let event = if change.status_changed() && pr.is_open() && !was_open {
+            ServerMessageType::PrOpened {
+                repo_id: pr.repository_id,
+                pr_number: pr.pr_number,
+                pr_id: pr.id,
+                title: pr.title.clone(),
+                author: pr.author.clone(),
+                reopened: !change.is_new(),
+            }
+        } else if change.status_changed() && !pr.is_open() {
+            ServerMessageType::PrClosed {
+                repo_id: pr.repository_id,
+                pr_number: pr.pr_number,
+                pr_id: pr.id,
+                merged: pr.status == PrStatus::Merged.as_str(),
+            }
+        } else {
+            ServerMessageType::PrUpdated {
+                repo_id: pr.repository_id,
+                pr_number: pr.pr_number,
+                pr_id: pr.id,
+                status: pr.status.clone(),
+                head_sha: pr.head_sha.clone(),
+            }
+        };
+        self.events.publish(event);
+    }
+
+    fn metadata_changed(change: &PullRequestChange) -> bool {
+        match &change.previous {
+            Some(prev) => {
+                prev.title != change.current.title
+                    || prev.status != change.current.status
+                    || prev.target_branch_id != change.current.target_branch_id
+                    || change.labels_changed()
+            }
+            None => true,
+        }
+    }
+}
+
+/// `labels` 중 `other`에 없는 라벨 (GitHub 라벨은 대소문자 구분 없음)
+fn label_difference(labels: &[String], other: &[String]) -> Vec<String> {
+    labels
+        .iter()
+        .filter(|label| !other.iter().any(|o| o.eq_ignore_ascii_case(label)))
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,245 @@
+use anyhow::{Result, anyhow};
+use reqwest::{RequestBuilder, Response, StatusCode, Url, header};
+use serde::Deserialize;
+use std::time::Duration;
+use tracing::debug;
+
+/// `/crumbIssuer/api/json` 응답
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Crumb {
+    crumb: String,
+    crumb_request_field: String,
+}
+
+/// 큐 아이템에서 실행이 시작된 빌드 정보
+#[derive(Debug, Clone, Deserialize)]
+pub struct QueueExecutable {
+    pub number: i32,
+    pub url: String,
+}
+
+/// `/queue/item/<id>/api/json` 응답
+#[derive(Debug, Clone, Deserialize)]
+pub struct QueueItem {
+    #[serde(default)]
+    pub cancelled: bool,
+    pub executable: Option<QueueExecutable>,
+    /// 대기 사유
+    pub why: Option<String>,
+}
+
+/// `/job/<name>/<number>/api/json` 응답
+#[derive(Debug, Clone, Deserialize)]
+pub struct JenkinsBuild {
+    pub building: bool,
+    /// 빌드 진행 중이면 None
+    pub result: Option<String>,
+    /// 소요 시간 (밀리초, 진행 중이면 0)
+    pub duration: i64,
+}
+
+/// Jenkins 원격 API 클라이언트
+///
+/// API 토큰 기반 Basic 인증을 사용하며, CSRF 보호가 켜진 서버를 위해
+/// 요청마다 crumb를 발급받아 헤더에 추가 (세션 쿠키 유지)
+#[derive(Debug, Clone)]
+pub struct JenkinsClient {
+    http: reqwest::Client,
+    base_url: Url,
+    user: String,
+    token: String,
+}
+
+impl JenkinsClient {
+    /// # Arguments
+    ///
+    /// * `base_url` - Jenkins 주소 (prefix 포함, 예: `http://localhost:5000/jenkins`)
+    /// * `user` - Jenkins 사용자
+    /// * `token` - Jenkins API 토큰
+    pub fn new(base_url: &str, user: &str, token: &str) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .cookie_store(true)
+            .timeout(Duration::from_secs(30))
+            .build()?;
+
+        Ok(JenkinsClient {
+            http,
+            base_url: Url::parse(base_url)?,
+            user: user.to_string(),
+            token: token.to_string(),
+        })
+    }
+
+    /// Job 주소 생성
+    ///
+    /// 폴더에 속한 Job은 `folder/sub/job` 형식으로 지정하며 `job/folder/job/sub/job/job`으로 변환
+    pub fn job_url(&self, job_name: &str) -> Result<Url> {
+        let mut url = self.base_url.clone();
+        {
+            let mut segments = url
+                .path_segments_mut()
+                .map_err(|_| anyhow!("Invalid Jenkins URL: {}", self.base_url))?;
+            segments.pop_if_empty();
+            for part in job_name.split('/').filter(|part| !part.is_empty()) {
+                segments.push("job").push(part);
+            }
+        }
+        Ok(url)
+    }
+
+    /// `buildWithParameters` 호출
+    ///
+    /// # Returns
+    ///
+    /// 큐 아이템 주소 (`Location` 헤더)
+    pub async fn trigger_build(
+        &self,
+        job_name: &str,
+        params: &[(String, String)],
+    ) -> Result<String> {
+        let url = Self::join(&self.job_url(job_name)?, "buildWithParameters")?;
+
+        let req = self.with_crumb(self.http.post(url.clone())).await?;
+        let resp = Self::check_status(req.form(params).send().await?).await?;
+
+        let location = resp
+            .headers()
+            .get(header::LOCATION)
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| anyhow!("Jenkins response has no queue location: {}", url))?;
+
+        debug!("Jenkins build queued: {} -> {}", job_name, location);
+        Ok(location.to_string())
+    }
+
+    /// 빌드 조회 (`<build_url>/api/json`)
+    ///
+    /// 트리거 후 job 이름이 바뀌어도 같은 빌드를 조회하도록 큐에서 받은 빌드 주소를 사용
+    ///
+    /// # Returns
+    ///
+    /// Jenkins에서 삭제된 빌드(404)는 None
+    pub async fn get_build(&self, build_url: &str) -> Result<Option<JenkinsBuild>> {
+        let url = Self::join(&Url::parse(build_url)?, "api/json")?;
+        let resp = self.get(url).send().await?;
+        if resp.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        Ok(Some(Self::check_status(resp).await?.json().await?))
+    }
+
+    /// 큐 아이템 조회
+    ///
+    /// # Returns
+    ///
+    /// 큐에서 빌드가 시작된 뒤 일정 시간이 지나 만료된 아이템(404)은 None
+    pub async fn get_queue_item(&self, queue_url: &str) -> Result<Option<QueueItem>> {
+        let url = Self::join(&Url::parse(queue_url)?, "api/json")?;
+        let resp = self.get(url).send().await?;
+        if resp.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        Ok(Some(Self::check_status(resp).await?.json().await?))
+    }
+
+    /// 진행 중인 빌드 중단 (`<build_url>/stop`)
+    pub async fn stop_build(&self, build_url: &str) -> Result<()> {
+        let url = Self::join(&Url::parse(build_url)?, "stop")?;
+        let req = self.with_crumb(self.http.post(url)).await?;
+        Self::check_status(req.send().await?).await?;
+        debug!("Jenkins build stopped: {}", build_url);
+        Ok(())
+    }
+
+    /// 큐 대기 중인 빌드 취소 (`/queue/cancelItem?id=<id>`)
+    pub async fn cancel_queue_item(&self, queue_url: &str) -> Result<()> {
+        let item_id = Url::parse(queue_url)?
+            .path_segments()
+            .and_then(|mut segments| segments.rfind(|segment| !segment.is_empty()))
+            .map(str::to_string)
+            .ok_or_else(|| anyhow!("Invalid Jenkins queue URL: {}", queue_url))?;
+        let mut url = Self::join(&self.base_url, "queue/cancelItem")?;
+        url.query_pairs_mut().append_pair("id", &item_id);
+
+        let req = self.with_crumb(self.http.post(url)).await?;
+        Self::check_status(req.send().await?).await?;
+        debug!("Jenkins queue item cancelled: {}", queue_url);
+        Ok(())
+    }
+
+    /// 큐 아이템이 실제 빌드로 시작될 때까지 대기
+    ///
+    /// # Returns
+    ///
+    /// 시간 내 빌드가 시작되지 않으면 None
+    pub async fn wait_for_build(
+        &self,
+        queue_url: &str,
+        timeout: Duration,
+    ) -> Result<Option<QueueExecutable>> {
+        let interval = Duration::from_secs(2);
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        loop {
+            let item = self
+                .get_queue_item(queue_url)
+                .await?
+                .ok_or_else(|| anyhow!("Jenkins queue item expired: {}", queue_url))?;
+            if item.cancelled {
+                return Err(anyhow!("Jenkins queue item cancelled: {}", queue_url));
+            }
+            if let Some(executable) = item.executable {
+                return Ok(Some(executable));
+            }
+            if tokio::time::Instant::now() + interval > deadline {
+                debug!(
+                    "Jenkins queue item still waiting: {} ({:?})",
+                    queue_url, item.why
+                );
+                return Ok(None);
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    fn get(&self, url: Url) -> RequestBuilder {
+        self.http.get(url).basic_auth(&self.user, Some(&self.token))
+    }
+
+    /// CSRF crumb 헤더 추가
+    ///
+    /// crumb issuer가 비활성화된 서버(404)는 그대로 진행
+    async fn with_crumb(&self, req: RequestBuilder) -> Result<RequestBuilder> {
+        let req = req.basic_auth(&self.user, Some(&self.token));
+
+        let url = Self::join(&self.base_url, "crumbIssuer/api/json")?;
+        let resp = self.get(url).send().await?;
+        if resp.status() == StatusCode::NOT_FOUND {
+            return Ok(req);
+        }
+
+        let crumb: Crumb = Self::check_status(resp).await?.json().await?;
+        Ok(req.header(crumb.crumb_request_field, crumb.crumb))
+    }
+
+    /// 디렉터리 형태 주소에 하위 경로 추가 (`.../job/name` + `api/json`)
+    fn join(url: &Url, path: &str) -> Result<Url> {
+        let mut base = url.clone();
+        if !base.path().ends_with('/') {
+            base.set_path(&format!("{}/", base.path()));
+        }
+        Ok(base.join(path)?)
+    }
+
+    async fn check_status(resp: Response) -> Result<Response> {
+        if resp.status().is_success() {
+            return Ok(resp);
+        }
+
+        let status = resp.status();
+        let url = resp.url().to_string();
+        let body = resp.text().await.unwrap_or_default();
+        Err(anyhow!("Jenkins API error: {} {} {}", status, url, body))
+    }
+}
@@ -0,0 +1,444 @@
+//! PR 코멘트 명령 (`/retest`, `/build <job>`, `/skip`) 처리
+//!
+//! 웹훅(`issue_comment`)과 폴링 양쪽에서 같은 경로로 처리하며, 처리한 코멘트는
+//! `pull_request_commands`에 기록하여 같은 코멘트를 다시 실행하지 않음
+
+use crate::{
+    db::{self, CommandResult, PullRequest, Repository},
+    github::{GithubClient, GithubIssueComment, Reaction},
+    jenkins::{BuildTriggerService, MatchedMapping},
+};
+use anyhow::Result;
+use chrono::{SecondsFormat, TimeDelta, Utc};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use tracing::{debug, error, info, warn};
+
+/// 명령 이름 설정 키 (`retest`, `build`, `skip` 순)
+pub const COMMAND_SETTING_KEYS: [&str; 3] = [
+    "comment_command_retest",
+    "comment_command_build",
+    "comment_command_skip",
+];
+
+/// 폴링 조회 구간을 이전 조회와 겹치게 하는 시간 (GitHub와 서버의 시계 차이 보정)
+const POLL_OVERLAP: TimeDelta = TimeDelta::seconds(60);
+
+/// PR 코멘트 명령
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommentCommand {
+    /// 조건에 맞는 매핑 전체 재빌드
+    Retest,
+    /// 지정한 잡만 빌드 (잡 이름이 없으면 빈 문자열)
+    Build(String),
+    /// 현재 head 커밋 빌드 건너뜀
+    Skip,
+}
+
+impl CommentCommand {
+    /// `pull_request_commands.command` 값
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommentCommand::Retest => "retest",
+            CommentCommand::Build(_) => "build",
+            CommentCommand::Skip => "skip",
+        }
+    }
+
+    pub fn argument(&self) -> Option<&str> {
+        match self {
+            CommentCommand::Build(job) if !job.is_empty() => Some(job),
+            _ => None,
+        }
+    }
+}
+
+/// 설정된 명령 이름 (빈 값이면 해당 명령 비활성화)
+#[derive(Debug, Clone)]
+pub struct CommandNames {
+    retest: String,
+    build: String,
+    skip: String,
+}
+
+impl CommandNames {
+    /// 시스템 설정에서 명령 이름 읽기 (설정이 없으면 기본 이름 사용)
+    pub fn from_settings(settings: &HashMap<String, String>) -> Self {
+        let [retest, build, skip] = COMMAND_SETTING_KEYS;
+        let name = |key: &str, default: &str| {
+            settings
+                .get(key)
+                .map_or(default, |value| value.trim())
+                .to_string()
+        };
+        CommandNames {
+            retest: name(retest, "/retest"),
+            build: name(build, "/build"),
+            skip: name(skip, "/skip"),
+        }
+    }
+
+    /// 코멘트 본문에서 첫 번째 명령 찾기
+    ///
+    /// 명령은 줄의 첫 단어여야 하며 (인용한 줄은 무시), `build` 명령은 다음 단어를 잡 이름으로 사용
+    pub fn parse(&self, body: &str) -> Option<CommentCommand> {
+        body.lines().find_map(|line| {
+            let mut words = line.split_whitespace();
+            let first = words.next()?;
+            if !self.retest.is_empty() && first == self.retest {
+                Some(CommentCommand::Retest)
+            } else if !self.build.is_empty() && first == self.build {
+                Some(CommentCommand::Build(
+                    words.next().unwrap_or_default().to_string(),
+                ))
+            } else if !self.skip.is_empty() && first == self.skip {
+                Some(CommentCommand::Skip)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// 명령 이름 설정값 검증 (`/`로 시작하고 공백이 없어야 하며, 빈 값은 비활성화)
+pub fn validate_command_name(value: &str) -> Result<()> {
+    if value.is_empty() {
+        return Ok(());
+    }
+    if !value.starts_with('/') || value.len() < 2 {
+        return Err(anyhow::anyhow!("must start with '/' followed by a name"));
+    }
+    if value.chars().any(char::is_whitespace) {
+        return Err(anyhow::anyhow!("must not contain whitespace"));
+    }
+    Ok(())
+}
+
+#[derive(Clone)]
+pub struct CommentCommandService {
+    pool: PgPool,
+    github: GithubClient,
+    triggers: BuildTriggerService,
+}
+
+impl CommentCommandService {
+    pub fn new(pool: &PgPool, github: GithubClient, triggers: BuildTriggerService) -> Self {
+        CommentCommandService {
+            pool: pool.clone(),
+            github,
+            triggers,
+        }
+    }
+
+    /// 마지막 조회 이후 작성된 저장소 코멘트의 명령 처리
+    ///
+    /// 처음 조회하는 저장소는 기존 코멘트를 실행하지 않도록 조회 시각만 기록
+    ///
+    /// # Returns
+    ///
+    /// 처리한 명령 수
+    pub async fn poll_comments(&self, repo: &Repository) -> Result<usize> {
+        let polled_at = Utc::now();
+        let Some(last_polled_at) = repo.comments_polled_at else {
+            db::Queries::set_comments_polled_at(&self.pool, repo.id, polled_at).await?;
+            return Ok(0);
+        };
+
+        let since = last_polled_at - POLL_OVERLAP;
+        let comments = self
+            .github
+            .list_issue_comments(
+                &repo.owner,
+                &repo.name,
+                &since.to_rfc3339_opts(SecondsFormat::Secs, true),
+            )
+            .await?;
+
+        let mut handled = 0;
+        // 수정된 예전 코멘트는 제외
+        for comment in comments
+            .iter()
+            .filter(|comment| comment.created_at >= since)
+        {
+            let Some(pr_number) = comment.issue_number() else {
+                continue;
+            };
+            match self.handle_comment(repo, pr_number, comment).await {
+                Ok(true) => handled += 1,
+                Ok(false) => {}
+                Err(e) => error!(
+                    "Failed to handle comment {} on {}/{}#{}: {}",
+                    comment.id, repo.owner, repo.name, pr_number, e
+                ),
+            }
+        }
+
+        db::Queries::set_comments_polled_at(&self.pool, repo.id, polled_at).await?;
+        Ok(handled)
+    }
+
+    /// 코멘트 하나의 명령 처리 후 결과를 코멘트 반응으로 표시
+    ///
+    /// # Returns
+    ///
+    /// 명령이 아니거나, 추적 중인 PR이 아니거나, 이미 처리한 코멘트면 false
+    pub async fn handle_comment(
+        &self,
+        repo: &Repository,
+        pr_number: i32,
+        comment: &GithubIssueComment,
+    ) -> Result<bool> {
+        let settings = db::Queries::get_system_settings(&self.pool).await?;
+        let Some(command) = CommandNames::from_settings(&settings).parse(&comment.body) else {
+            return Ok(false);
+        };
+        let Some(author) = comment.author() else {
+            return Ok(false);
+        };
+        let Some(pr) =
+            db::Queries::get_pull_request_by_number(&self.pool, repo.id, pr_number).await?
+        else {
+            debug!(
+                "Comment command ignored for untracked issue: {}/{}#{}",
+                repo.owner, repo.name, pr_number
+            );
+            return Ok(false);
+        };
+
+        let Some(record) = db::Queries::create_pull_request_command(
+            &self.pool,
+            pr.id,
+            comment.id,
+            author,
+            command.as_str(),
+            command.argument(),
+        )
+        .await?
+        else {
+            debug!("Comment command already handled: {}", comment.id);
+            return Ok(false);
+        };
+
+        let (result, message) = match self.run(repo, &pr, author, &command).await {
+            Ok(outcome) => outcome,
+            Err(e) => (CommandResult::Failed, e.to_string()),
+        };
+        info!(
+            "Comment command {:?} by @{} on {}/{}#{}: {} ({})",
+            command,
+            author,
+            repo.owner,
+            repo.name,
+            pr_number,
+            result.as_str(),
+            message
+        );
+        db::Queries::complete_pull_request_command(&self.pool, record.id, result, &message).await?;
+
+        let reaction = match result {
+            CommandResult::Triggered | CommandResult::Skipped => Reaction::ThumbsUp,
+            CommandResult::Denied => Reaction::ThumbsDown,
+            CommandResult::Rejected | CommandResult::Failed => Reaction::Confused,
+        };
+        if let Err(e) = self
+            .github
+            .create_comment_reaction(&repo.owner, &repo.name, comment.id, reaction)
+            .await
+        {
+            warn!("Failed to react to comment {}: {}", comment.id, e);
+        }
+        Ok(true)
+    }
+
+    /// 작성자 권한 확인 후 명령 실행
+    ///
+    /// # Returns
+    ///
+    /// (처리 결과, 기록할 메시지)
+    async fn run(
+        &self,
+        repo: &Repository,
+        pr: &PullRequest,
+        author: &str,
+        command: &CommentCommand,
+    ) -> Result<(CommandResult, String)> {
+        let permission = self
+            .github
+            .get_collaborator_permission(&repo.owner, &repo.name, author)
+            .await?;
+        if !permission.can_write() {
+            return Ok((
+                CommandResult::Denied,
+                format!("@{} does not have write access", author),
+            ));
+        }
+        if !pr.is_open() {
+            return Ok((
+                CommandResult::Rejected,
+                "Pull request is not open".to_string(),
+            ));
+        }
+
+        let mappings = db::Queries::get_jenkins_mappings(&self.pool, repo.id).await?;
+        let mappings = match command {
+            CommentCommand::Build(job) if job.is_empty() => {
+                return Ok((CommandResult::Rejected, "Job name is required".to_string()));
+            }
+            CommentCommand::Build(job) => {
+                match mappings
+                    .into_iter()
+                    .find(|mapping| mapping.jenkins_job_name == *job)
+                {
+                    Some(mapping) => vec![MatchedMapping {
+                        mapping,
+                        matched_paths: None,
+                    }],
+                    None => {
+                        return Ok((
+                            CommandResult::Rejected,
+                            format!("Unknown Jenkins job: {}", job),
+                        ));
+                    }
+                }
+            }
+            CommentCommand::Retest | CommentCommand::Skip => {
+                self.triggers.matching_mappings(repo, &mappings, pr).await?
+            }
+        };
+        if mappings.is_empty() {
+            return Ok((
+                CommandResult::Rejected,
+                "No Jenkins mapping matches this pull request".to_string(),
+            ));
+        }
+        let jobs = job_names(&mappings);
+
+        match command {
+            CommentCommand::Retest => {
+                self.spawn_rebuilds(mappings, pr, format!("Retest requested by @{}", author));
+                Ok((CommandResult::Triggered, format!("Triggered {}", jobs)))
+            }
+            CommentCommand::Build(_) => {
+                self.spawn_rebuilds(mappings, pr, format!("Build requested by @{}", author));
+                Ok((CommandResult::Triggered, format!("Triggered {}", jobs)))
+            }
+            CommentCommand::Skip => {
+                let message = format!("Skipped by @{}", author);
+                for matched in &mappings {
+                    self.triggers
+                        .skip_pull_request(&matched.mapping, pr, &message)
+                        .await?;
+                }
+                Ok((CommandResult::Skipped, format!("Skipped {}", jobs)))
+            }
+        }
+    }
+
+    /// 큐 대기로 코멘트 처리가 지연되지 않도록 매핑마다 별도 태스크로 재빌드
+    fn spawn_rebuilds(&self, mappings: Vec<MatchedMapping>, pr: &PullRequest, message: String) {
+        for matched in mappings {
+            let triggers = self.triggers.clone();
+            let pr = pr.clone();
+            let message = message.clone();
+            tokio::spawn(async move {
+                if let Err(e) = triggers
+                    .rebuild_pull_request(
+                        &matched.mapping,
+                        &pr,
+                        &message,
+                        matched.matched_paths.as_deref(),
+                    )
+                    .await
+                {
+                    error!(
+                        "Failed to record build trigger: PR #{} ({}): {}",
+                        pr.pr_number, matched.mapping.jenkins_job_name, e
+                    );
+                }
+            });
+        }
+    }
+}
+
+/// 메시지 표시용 잡 이름 목록
+fn job_names(mappings: &[MatchedMapping]) -> String {
+    mappings
+        .iter()
+        .map(|matched| matched.mapping.jenkins_job_name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_names() -> CommandNames {
+        CommandNames::from_settings(&HashMap::new())
+    }
+
+    #[test]
+    fn parses_commands_at_line_start() {
+        let names = default_names();
+        assert_eq!(names.parse("/retest"), Some(CommentCommand::Retest));
+        assert_eq!(names.parse("  /skip  "), Some(CommentCommand::Skip));
+        assert_eq!(
+            names.parse("/build my-job extra words"),
+            Some(CommentCommand::Build("my-job".to_string()))
+        );
+        assert_eq!(
+            names.parse("/build"),
+            Some(CommentCommand::Build(String::new()))
+        );
+    }
+
+    #[test]
+    fn finds_first_command_line() {
+        let names = default_names();
+        let body = "Flaky test again.\n\n/retest\n/skip";
+        assert_eq!(names.parse(body), Some(CommentCommand::Retest));
+    }
+
+    #[test]
+    fn ignores_quoted_and_inline_commands() {
+        let names = default_names();
+        assert_eq!(names.parse("> /retest"), None);
+        assert_eq!(names.parse(">/retest"), None);
+        assert_eq!(names.parse("please /retest this"), None);
+        assert_eq!(names.parse("/retesting"), None);
+        assert_eq!(names.parse("> /skip\nthanks"), None);
+        assert_eq!(
+            names.parse("> /skip\n/retest"),
+            Some(CommentCommand::Retest)
+        );
+    }
+
+    #[test]
+    fn uses_configured_names_and_disables_empty_ones() {
+        let settings = HashMap::from([
+            (
+                "comment_command_retest".to_string(),
+                " /ci-retest ".to_string(),
+            ),
+            ("comment_command_skip".to_string(), String::new()),
+        ]);
+        let names = CommandNames::from_settings(&settings);
+        assert_eq!(names.parse("/ci-retest"), Some(CommentCommand::Retest));
+        assert_eq!(names.parse("/retest"), None);
+        assert_eq!(names.parse("/skip"), None);
+        assert_eq!(names.parse(""), None);
+        assert_eq!(
+            names.parse("/build job"),
+            Some(CommentCommand::Build("job".to_string()))
+        );
+    }
+
+    #[test]
+    fn validates_command_names() {
+        assert!(validate_command_name("").is_ok());
+        assert!(validate_command_name("/retest").is_ok());
+        assert!(validate_command_name("retest").is_err());
+        assert!(validate_command_name("/").is_err());
+        assert!(validate_command_name("/re test").is_err());
+    }
+}
@@ -0,0 +1,13 @@
+// 하위 모듈 선언
+mod client;
+mod comment_command;
+mod template;
+mod trigger;
+mod watcher;
+
+// 공개 API
+pub use client::*;
+pub use comment_command::*;
+pub use template::*;
+pub use trigger::*;
+pub use watcher::*;
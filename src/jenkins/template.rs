@@ -0,0 +1,171 @@
+//! Jenkins 빌드 파라미터 템플릿
+//!
+//! 값 안의 `{{변수}}`를 트리거 대상의 값으로 치환
+//! (예: `pull/{{pr_number}}/head` -> `pull/42/head`)
+
+use anyhow::{Result, anyhow};
+
+/// 템플릿에서 사용할 수 있는 변수
+pub const TEMPLATE_VARIABLES: &[&str] = &[
+    "pr_number",
+    "head_sha",
+    "source_branch",
+    "target_branch",
+    "author",
+    "repo_owner",
+    "repo_name",
+];
+
+/// 템플릿 변수 값
+///
+/// 브랜치 빌드처럼 PR이 없으면 `pr_number`와 `author`는 빈 문자열로 치환
+#[derive(Debug, Clone)]
+pub struct TemplateValues<'a> {
+    pub pr_number: Option<i32>,
+    pub head_sha: &'a str,
+    pub source_branch: &'a str,
+    pub target_branch: &'a str,
+    pub author: Option<&'a str>,
+    pub repo_owner: &'a str,
+    pub repo_name: &'a str,
+}
+
+impl TemplateValues<'_> {
+    fn get(&self, variable: &str) -> Option<String> {
+        let value = match variable {
+            "pr_number" => self
+                .pr_number
+                .map(|number| number.to_string())
+                .unwrap_or_default(),
+            "head_sha" => self.head_sha.to_string(),
+            "source_branch" => self.source_branch.to_string(),
+            "target_branch" => self.target_branch.to_string(),
+            "author" => self.author.unwrap_or_default().to_string(),
+            "repo_owner" => self.repo_owner.to_string(),
+            "repo_name" => self.repo_name.to_string(),
+            _ => return None,
+        };
+        Some(value)
+    }
+}
+
+/// 템플릿 검증 (저장 시 사용)
+///
+/// 닫히지 않은 `{{`나 알 수 없는 변수가 있으면 에러
+pub fn validate_template(template: &str) -> Result<()> {
+    for variable in variables(template)? {
+        if !TEMPLATE_VARIABLES.contains(&variable) {
+            return Err(anyhow!(
+                "unknown placeholder {{{{{}}}}} (available: {})",
+                variable,
+                TEMPLATE_VARIABLES.join(", ")
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// 템플릿의 변수를 값으로 치환
+pub fn render_template(template: &str, values: &TemplateValues) -> Result<String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        rendered.push_str(&rest[..start]);
+        let (variable, after) = split_variable(&rest[start + 2..], template)?;
+        let value = values
+            .get(variable)
+            .ok_or_else(|| anyhow!("unknown placeholder {{{{{}}}}}", variable))?;
+        rendered.push_str(&value);
+        rest = after;
+    }
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+/// 템플릿에 쓰인 변수 이름 목록
+fn variables(template: &str) -> Result<Vec<&str>> {
+    let mut variables = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let (variable, after) = split_variable(&rest[start + 2..], template)?;
+        variables.push(variable);
+        rest = after;
+    }
+    Ok(variables)
+}
+
+/// `{{` 다음 문자열을 변수 이름과 `}}` 이후 문자열로 분리 (변수 이름 앞뒤 공백 허용)
+fn split_variable<'a>(rest: &'a str, template: &str) -> Result<(&'a str, &'a str)> {
+    let end = rest
+        .find("}}")
+        .ok_or_else(|| anyhow!("unclosed placeholder in {:?}", template))?;
+    Ok((rest[..end].trim(), &rest[end + 2..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pr_values() -> TemplateValues<'static> {
+        TemplateValues {
+            pr_number: Some(42),
+            head_sha: "abc123",
+            source_branch: "feature/login",
+            target_branch: "main",
+            author: Some("octocat"),
+            repo_owner: "acme",
+            repo_name: "widgets",
+        }
+    }
+
+    #[test]
+    fn renders_variables() {
+        let rendered = render_template("pull/{{pr_number}}/head", &pr_values()).unwrap();
+        assert_eq!(rendered, "pull/42/head");
+
+        let rendered =
+            render_template("{{ repo_owner }}/{{repo_name}}@{{head_sha}}", &pr_values()).unwrap();
+        assert_eq!(rendered, "acme/widgets@abc123");
+    }
+
+    #[test]
+    fn renders_text_without_placeholders_unchanged() {
+        assert_eq!(render_template("", &pr_values()).unwrap(), "");
+        assert_eq!(
+            render_template("plain } text }}", &pr_values()).unwrap(),
+            "plain } text }}"
+        );
+    }
+
+    #[test]
+    fn renders_missing_pull_request_values_as_empty() {
+        let values = TemplateValues {
+            pr_number: None,
+            author: None,
+            ..pr_values()
+        };
+        let rendered = render_template("[{{pr_number}}][{{author}}]", &values).unwrap();
+        assert_eq!(rendered, "[][]");
+    }
+
+    #[test]
+    fn rejects_unclosed_placeholder() {
+        assert!(render_template("pull/{{pr_number/head", &pr_values()).is_err());
+        assert!(validate_template("pull/{{pr_number/head").is_err());
+        assert!(validate_template("{{head_sha}} {{").is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_variable() {
+        assert!(render_template("{{branch}}", &pr_values()).is_err());
+        assert!(validate_template("{{branch}}").is_err());
+    }
+
+    #[test]
+    fn accepts_known_variables() {
+        for variable in TEMPLATE_VARIABLES {
+            assert!(validate_template(&format!("x-{{{{{}}}}}-y", variable)).is_ok());
+        }
+        assert!(validate_template("no placeholders").is_ok());
+    }
+}
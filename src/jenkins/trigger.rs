@@ -0,0 +1,1059 @@
+//! PR/브랜치 빌드 트리거 및 `build_triggers` 이력 기록
+
+use crate::{
+    config::Config,
+    db::{
+        self, Branch, BuildTrigger, JenkinsMapping, PullRequest, Repository, SkipReason, Tag,
+        TagRule, TriggerStatus,
+    },
+    github::{CommitState, CommitStatus, GithubClient, PullRequestChange},
+    jenkins::{JenkinsClient, TemplateValues, render_template},
+    rules::{BuildContext, MappingFilter, Pattern},
+    ws::{event_bus::EventBus, ws_message::ServerMessageType},
+};
+use anyhow::Result;
+use sqlx::PgPool;
+use std::{fmt, time::Duration};
+use tracing::{debug, error, info, warn};
+
+/// 큐 아이템이 빌드로 시작될 때까지 기다리는 최대 시간
+const QUEUE_WAIT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// 자동 PR 빌드 설정에 따른 결정
+enum AutoBuildDecision {
+    Build,
+    /// 건너뜀 (사유, 트리거 메시지에 남길 설명)
+    Skip(SkipReason, String),
+}
+
+/// 열린 PR 자동 빌드 사유
+#[derive(Debug, Clone)]
+pub enum PullRequestTrigger {
+    /// 새 PR이거나 head 커밋이 바뀜
+    HeadChanged,
+    /// head 커밋은 그대로이고 초안에서 리뷰 준비 완료로 바뀜
+    ReadyForReview,
+    /// head 커밋은 그대로이고 라벨이 추가/제거됨
+    LabelsChanged {
+        added: Vec<String>,
+        removed: Vec<String>,
+    },
+}
+
+impl PullRequestTrigger {
+    /// PR 변경에 해당하는 자동 빌드 사유 (빌드할 변경이 아니면 None)
+    fn for_change(change: &PullRequestChange) -> Option<Self> {
+        if !change.current.is_open() {
+            None
+        } else if change.head_changed() {
+            Some(PullRequestTrigger::HeadChanged)
+        } else if change.ready_for_review() {
+            Some(PullRequestTrigger::ReadyForReview)
+        } else if change.labels_changed() {
+            Some(PullRequestTrigger::LabelsChanged {
+                added: change.added_labels(),
+                removed: change.removed_labels(),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// 라벨 변경이면 라벨 조건이 새로 충족될 수 있는 매핑인지 여부 (그 외 사유는 항상 true)
+    fn applies_to(&self, mapping: &JenkinsMapping) -> bool {
+        match self {
+            PullRequestTrigger::LabelsChanged { added, removed } => {
+                MappingFilter::new(mapping).is_ok_and(|filter| filter.unblocked_by(added, removed))
+            }
+            _ => true,
+        }
+    }
+}
+
+/// PR 트리거 조건을 만족한 매핑
+#[derive(Debug, Clone)]
+pub struct MatchedMapping {
+    pub mapping: JenkinsMapping,
+    /// 경로 조건에 일치한 변경 파일 (경로 조건이 없으면 None)
+    pub matched_paths: Option<Vec<String>>,
+}
+
+/// 빌드 대상
+#[derive(Debug, Clone, Copy)]
+pub enum BuildTarget<'a> {
+    PullRequest(&'a PullRequest),
+    Branch(&'a Branch),
+    /// 태그 규칙에 맞는 새 태그
+    Tag {
+        tag: &'a Tag,
+        rule: &'a TagRule,
+    },
+}
+
+impl fmt::Display for BuildTarget<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildTarget::PullRequest(pr) => write!(f, "PR #{}", pr.pr_number),
+            BuildTarget::Branch(branch) => write!(f, "branch {}", branch.name),
+            BuildTarget::Tag { tag, .. } => write!(f, "tag {}", tag.name),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct BuildTriggerService {
+    pool: PgPool,
+    /// 빌드 진행 상황을 커밋 상태로 보고하기 위한 클라이언트
+    github: GithubClient,
+    events: EventBus,
+    jenkins_url: String,
+    jenkins_user: String,
+    jenkins_token: String,
+}
+
+impl BuildTriggerService {
+    pub fn new(pool: &PgPool, config: &Config, github: GithubClient, events: EventBus) -> Self {
+        BuildTriggerService {
+            pool: pool.clone(),
+            github,
+            events,
+            jenkins_url: config.jenkins_url.clone(),
+            jenkins_user: config.jenkins_user.clone(),
+            jenkins_token: config.jenkins_token.clone(),
+        }
+    }
+
+    /// 매핑에 지정된 Jenkins 서버용 클라이언트 (없으면 전역 설정 사용)
+    pub fn client_for(&self, mapping: &JenkinsMapping) -> Result<JenkinsClient> {
+        let base_url = mapping
+            .jenkins_url
+            .as_deref()
+            .filter(|url| !url.is_empty())
+            .unwrap_or(&self.jenkins_url);
+        JenkinsClient::new(base_url, &self.jenkins_user, &self.jenkins_token)
+    }
+
+    /// 자동 트리거가 켜진 저장소 매핑 목록
+    async fn auto_trigger_mappings(&self, repo_id: i32) -> Result<Vec<JenkinsMapping>> {
+        let mut mappings = db::Queries::get_jenkins_mappings(&self.pool, repo_id).await?;
+        mappings.retain(|mapping| mapping.auto_trigger);
+        Ok(mappings)
+    }
+
+    /// 저장소에서 감지된 PR 변경 중 head 커밋이 바뀌었거나, 초안에서 리뷰 준비 완료로
+    /// 바뀌었거나, 라벨이 바뀐 열린 PR에 대해 자동 빌드 트리거
+    ///
+    /// 조건에 맞는 매핑마다 트리거하며 (라벨 변경은 필요한 라벨이 추가되었거나 제외 라벨이
+    /// 제거된 매핑만), 큐 대기로 폴링이 지연되지 않도록 PR/매핑마다 별도 태스크로 실행
+    pub async fn handle_pull_request_changes(
+        &self,
+        repo: &Repository,
+        changes: &[PullRequestChange],
+    ) -> Result<()> {
+        let changed: Vec<(&PullRequest, PullRequestTrigger)> = changes
+            .iter()
+            .filter_map(|change| Some((&change.current, PullRequestTrigger::for_change(change)?)))
+            .collect();
+        if changed.is_empty() {
+            return Ok(());
+        }
+
+        let mappings = self.auto_trigger_mappings(repo.id).await?;
+        if mappings.is_empty() {
+            return Ok(());
+        }
+
+        for (pr, reason) in changed {
+            let service = self.clone();
+            let repo = repo.clone();
+            let mappings = mappings.clone();
+            let pr = pr.clone();
+            tokio::spawn(async move {
+                let mappings = match service.matching_mappings(&repo, &mappings, &pr).await {
+                    Ok(mappings) => mappings,
+                    Err(e) => {
+                        error!(
+                            "Failed to match Jenkins mappings: PR #{}: {}",
+                            pr.pr_number, e
+                        );
+                        return;
+                    }
+                };
+                for matched in mappings
+                    .into_iter()
+                    .filter(|matched| reason.applies_to(&matched.mapping))
+                {
+                    let service = service.clone();
+                    let pr = pr.clone();
+                    let reason = reason.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = service
+                            .trigger_pull_request(
+                                &matched.mapping,
+                                &pr,
+                                &reason,
+                                matched.matched_paths.as_deref(),
+                            )
+                            .await
+                        {
+                            error!(
+                                "Failed to record build trigger: PR #{} ({}): {}",
+                                pr.pr_number, matched.mapping.jenkins_job_name, e
+                            );
+                        }
+                    });
+                }
+            });
+        }
+        Ok(())
+    }
+
+    /// 매핑 중 PR이 트리거 조건을 만족하는 매핑과 일치한 변경 파일 목록
+    ///
+    /// 경로 조건이 있는 매핑이 있을 때만 GitHub에서 변경 파일 목록을 조회
+    pub async fn matching_mappings(
+        &self,
+        repo: &Repository,
+        mappings: &[JenkinsMapping],
+        pr: &PullRequest,
+    ) -> Result<Vec<MatchedMapping>> {
+        let filters: Vec<(&JenkinsMapping, MappingFilter)> = mappings
+            .iter()
+            .filter_map(|mapping| match MappingFilter::new(mapping) {
+                Ok(filter) => Some((mapping, filter)),
+                Err(e) => {
+                    warn!("Invalid Jenkins mapping filter {}: {}", mapping.id, e);
+                    None
+                }
+            })
+            .collect();
+
+        let source = db::Queries::get_branch(&self.pool, pr.source_branch_id).await?;
+        let target = db::Queries::get_branch(&self.pool, pr.target_branch_id).await?;
+        let changed_paths = if filters
+            .iter()
+            .any(|(_, filter)| filter.needs_changed_paths())
+        {
+            Some(
+                self.github
+                    .list_pull_request_files(&repo.owner, &repo.name, pr.pr_number)
+                    .await?,
+            )
+        } else {
+            None
+        };
+
+        let context = BuildContext {
+            target_branch: &target.name,
+            source_branch: source_branch_name(&source),
+            labels: &pr.labels,
+            changed_paths: changed_paths.as_deref(),
+        };
+        Ok(filters
+            .into_iter()
+            .filter(|(_, filter)| filter.matches(&context))
+            .map(|(mapping, filter)| MatchedMapping {
+                mapping: mapping.clone(),
+                matched_paths: filter.matched_paths(&context),
+            })
+            .collect())
+    }
+
+    /// 브랜치 head가 바뀌었을 때 감시 규칙에 맞으면 자동 빌드 트리거
+    ///
+    /// 대상 브랜치 조건에 맞는 매핑마다 트리거하며 (PR에만 있는 정보가 필요한 매핑,
+    /// 즉 필요한 라벨이나 경로 조건이 지정된 매핑은 제외),
+    /// 큐 대기로 동기화가 지연되지 않도록 매핑마다 별도 태스크로 실행
+    pub async fn handle_branch_update(&self, repo: &Repository, branch: &Branch) -> Result<()> {
+        let rules = db::Queries::get_branch_watch_rules(&self.pool, repo.id).await?;
+        let watched = rules.iter().any(|rule| match Pattern::new(&rule.pattern) {
+            Ok(pattern) => pattern.matches(&branch.name),
+            Err(e) => {
+                warn!("Invalid branch watch rule {}: {}", rule.id, e);
+                false
+            }
+        });
+        if !watched {
+            return Ok(());
+        }
+
+        let context = BuildContext {
+            target_branch: &branch.name,
+            source_branch: &branch.name,
+            labels: &[],
+            changed_paths: None,
+        };
+        for mapping in self.auto_trigger_mappings(repo.id).await? {
+            match MappingFilter::new(&mapping) {
+                // 브랜치 push는 변경 파일 목록이 없어 경로 조건을 판단할 수 없음
+                Ok(filter) if filter.matches(&context) && !filter.needs_changed_paths() => {}
+                Ok(_) => continue,
+                Err(e) => {
+                    warn!("Invalid Jenkins mapping filter {}: {}", mapping.id, e);
+                    continue;
+                }
+            }
+
+            let service = self.clone();
+            let branch = branch.clone();
+            tokio::spawn(async move {
+                if let Err(e) = service.trigger_branch(&mapping, &branch).await {
+                    error!(
+                        "Failed to record build trigger: branch {} ({}): {}",
+                        branch.name, mapping.jenkins_job_name, e
+                    );
+                }
+            });
+        }
+        Ok(())
+    }
+
+    /// 새 태그(또는 다른 커밋으로 옮겨진 태그)가 태그 규칙에 맞으면 릴리스 빌드 트리거
+    ///
+    /// 여러 규칙이 맞으면 먼저 등록된 규칙 하나만 적용하며, Jenkins 서버와 커밋 상태
+    /// context는 규칙에 저장된 매핑을 따름.
+    /// 큐 대기로 동기화가 지연되지 않도록 별도 태스크로 실행
+    pub async fn handle_tag_update(&self, repo: &Repository, tag: &Tag) -> Result<()> {
+        let rules = db::Queries::get_tag_rules(&self.pool, repo.id).await?;
+        let rule = rules
+            .into_iter()
+            .find(|rule| match Pattern::new(&rule.pattern) {
+                Ok(pattern) => pattern.matches(&tag.name),
+                Err(e) => {
+                    warn!("Invalid tag rule {}: {}", rule.id, e);
+                    false
+                }
+            });
+        let Some(rule) = rule else {
+            return Ok(());
+        };
+
+        let Some(mapping) =
+            db::Queries::get_jenkins_mapping(&self.pool, rule.jenkins_mapping_id).await?
+        else {
+            warn!(
+                "Tag rule {} matched {} but Jenkins mapping {} no longer exists",
+                rule.id, tag.name, rule.jenkins_mapping_id
+            );
+            return Ok(());
+        };
+
+        let service = self.clone();
+        let tag = tag.clone();
+        tokio::spawn(async move {
+            if let Err(e) = service.trigger_tag(&mapping, &rule, &tag).await {
+                error!("Failed to record build trigger: tag {}: {}", tag.name, e);
+            }
+        });
+        Ok(())
+    }
+
+    /// 태그 커밋으로 태그 규칙의 Jenkins job 빌드 트리거
+    ///
+    /// # Returns
+    ///
+    /// 이미 같은 태그/커밋으로 트리거된 이력이 있으면 None
+    pub async fn trigger_tag(
+        &self,
+        mapping: &JenkinsMapping,
+        rule: &TagRule,
+        tag: &Tag,
+    ) -> Result<Option<BuildTrigger>> {
+        let trigger = match db::Queries::create_tag_build_trigger(
+            &self.pool,
+            tag.id,
+            mapping.id,
+            &tag.commit_sha,
+            &rule.jenkins_job_name,
+            Some("Tag created"),
+        )
+        .await?
+        {
+            Some(trigger) => trigger,
+            None => return Ok(None),
+        };
+
+        let mapping = with_job(mapping, &rule.jenkins_job_name);
+        Ok(Some(
+            self.execute(&mapping, BuildTarget::Tag { tag, rule }, trigger)
+                .await?,
+        ))
+    }
+
+    /// 브랜치의 현재 head 커밋으로 Jenkins 빌드 트리거 (자동 트리거)
+    ///
+    /// # Returns
+    ///
+    /// 이미 같은 커밋이 같은 매핑으로 트리거된 이력이 있으면 None
+    pub async fn trigger_branch(
+        &self,
+        mapping: &JenkinsMapping,
+        branch: &Branch,
+    ) -> Result<Option<BuildTrigger>> {
+        let trigger = match db::Queries::create_branch_build_trigger(
+            &self.pool,
+            branch.id,
+            mapping.id,
+            &branch.head_sha,
+            Some("Branch push"),
+        )
+        .await?
+        {
+            Some(trigger) => trigger,
+            None => return Ok(None),
+        };
+
+        Ok(Some(
+            self.execute(mapping, BuildTarget::Branch(branch), trigger)
+                .await?,
+        ))
+    }
+
+    /// PR의 현재 head 커밋으로 Jenkins 빌드 트리거 (자동 트리거)
+    ///
+    /// 매핑의 디바운스/초안/`[skip ci]`/이전 빌드 중단 설정을 적용하고 결정을 트리거
+    /// 메시지에 기록. 리뷰 준비 완료로 바뀐 PR은 초안이라 건너뛴 커밋을 새 시도로 다시
+    /// 트리거하고, 라벨 변경은 아직 트리거되지 않은 커밋만 트리거
+    ///
+    /// # Returns
+    ///
+    /// 이미 같은 커밋이 같은 매핑으로 트리거된 이력이 있거나, 리뷰 준비 완료 시 최근
+    /// 시도가 초안이라 건너뛴 트리거가 아니면 None
+    pub async fn trigger_pull_request(
+        &self,
+        mapping: &JenkinsMapping,
+        pr: &PullRequest,
+        reason: &PullRequestTrigger,
+        matched_paths: Option<&[String]>,
+    ) -> Result<Option<BuildTrigger>> {
+        let message = match reason {
+            PullRequestTrigger::HeadChanged => None,
+            PullRequestTrigger::ReadyForReview => {
+                if !mapping.skip_draft {
+                    return Ok(None);
+                }
+                // 자동 트리거는 커밋마다 한 번이므로, 초안이라 건너뛴 커밋만 다시 트리거
+                let latest = db::Queries::get_latest_build_trigger(
+                    &self.pool,
+                    pr.id,
+                    mapping.id,
+                    &pr.head_sha,
+                )
+                .await?;
+                if latest.is_some_and(|latest| !skipped_as_draft(&latest)) {
+                    return Ok(None);
+                }
+                let trigger = db::Queries::create_retry_build_trigger(
+                    &self.pool,
+                    pr.id,
+                    mapping.id,
+                    &pr.head_sha,
+                    Some("Ready for review"),
+                )
+                .await?;
+                let trigger = self.record_matched_paths(trigger, matched_paths).await?;
+                return self.run_auto_build(mapping, pr, trigger).await.map(Some);
+            }
+            PullRequestTrigger::LabelsChanged { added, removed } => {
+                Some(label_change_message(added, removed))
+            }
+        };
+
+        match db::Queries::create_build_trigger(
+            &self.pool,
+            pr.id,
+            mapping.id,
+            &pr.head_sha,
+            message.as_deref(),
+        )
+        .await?
+        {
+            Some(trigger) => {
+                let trigger = self.record_matched_paths(trigger, matched_paths).await?;
+                self.run_auto_build(mapping, pr, trigger).await.map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// 경로 조건에 일치한 변경 파일을 트리거 이력에 기록 (경로 조건이 없으면 그대로 반환)
+    async fn record_matched_paths(
+        &self,
+        trigger: BuildTrigger,
+        matched_paths: Option<&[String]>,
+    ) -> Result<BuildTrigger> {
+        match matched_paths {
+            Some(paths) => {
+                db::Queries::set_build_trigger_matched_paths(&self.pool, trigger.id, paths).await
+            }
+            None => Ok(trigger),
+        }
+    }
+
+    /// 자동 빌드 설정을 적용한 뒤 빌드하거나 건너뛴 트리거로 기록
+    async fn run_auto_build(
+        &self,
+        mapping: &JenkinsMapping,
+        pr: &PullRequest,
+        trigger: BuildTrigger,
+    ) -> Result<BuildTrigger> {
+        let mut messages: Vec<String> = trigger.trigger_message.iter().cloned().collect();
+        let (decision, notes) = self.apply_auto_build_rules(mapping, pr, &trigger).await?;
+        messages.extend(notes);
+        if let AutoBuildDecision::Skip(reason, message) = decision {
+            messages.push(message);
+            let trigger = db::Queries::skip_build_trigger(
+                &self.pool,
+                trigger.id,
+                reason,
+                &messages.join("; "),
+            )
+            .await?;
+            info!(
+                "Build skipped: {} PR #{} ({}): {:?}",
+                mapping.jenkins_job_name, pr.pr_number, trigger.commit_sha, trigger.trigger_message
+            );
+            return Ok(trigger);
+        }
+
+        let trigger = if messages.is_empty() {
+            trigger
+        } else {
+            db::Queries::set_build_trigger_message(&self.pool, trigger.id, &messages.join("; "))
+                .await?
+        };
+        self.execute(mapping, BuildTarget::PullRequest(pr), trigger)
+            .await
+    }
+
+    /// 자동 PR 빌드 설정 적용
+    ///
+    /// 디바운스 대기 후 PR을 다시 읽어 초안/`[skip ci]` 여부를 확인하고, 빌드할 경우
+    /// 같은 PR의 진행 중인 이전 커밋 빌드를 중단
+    ///
+    /// # Returns
+    ///
+    /// 빌드 여부와 트리거 메시지에 남길 결정 목록
+    async fn apply_auto_build_rules(
+        &self,
+        mapping: &JenkinsMapping,
+        pr: &PullRequest,
+        trigger: &BuildTrigger,
+    ) -> Result<(AutoBuildDecision, Vec<String>)> {
+        let mut notes = Vec::new();
+
+        let mut pr = pr.clone();
+        if mapping.debounce_seconds > 0 {
+            tokio::time::sleep(Duration::from_secs(mapping.debounce_seconds as u64)).await;
+            pr = db::Queries::get_pull_request(&self.pool, pr.id).await?;
+            if pr.head_sha != trigger.commit_sha {
+                let reason = format!(
+                    "Skipped: superseded by {} within {}s debounce window",
+                    short_sha(&pr.head_sha),
+                    mapping.debounce_seconds
+                );
+                return Ok((
+                    AutoBuildDecision::Skip(SkipReason::Superseded, reason),
+                    notes,
+                ));
+            }
+            if !pr.is_open() {
+                let reason = "Skipped: pull request closed during debounce window".to_string();
+                return Ok((AutoBuildDecision::Skip(SkipReason::Closed, reason), notes));
+            }
+            notes.push(format!("Debounced {}s", mapping.debounce_seconds));
+        }
+
+        if mapping.skip_draft && pr.is_draft() {
+            let reason = "Skipped: draft pull request".to_string();
+            return Ok((AutoBuildDecision::Skip(SkipReason::Draft, reason), notes));
+        }
+
+        if mapping.skip_ci_commits {
+            let repo = db::Queries::get_repository(&self.pool, pr.repository_id).await?;
+            match self
+                .github
+                .get_commit_message(&repo.owner, &repo.name, &trigger.commit_sha)
+                .await
+            {
+                Ok(message) if has_skip_ci(&message) => {
+                    let reason = "Skipped: commit message contains [skip ci]".to_string();
+                    return Ok((AutoBuildDecision::Skip(SkipReason::SkipCi, reason), notes));
+                }
+                Ok(_) => {}
+                // 메시지를 확인하지 못하면 빌드는 진행
+                Err(e) => {
+                    warn!(
+                        "Failed to get commit message: {} ({}): {}",
+                        trigger.commit_sha, mapping.jenkins_job_name, e
+                    );
+                    notes.push("Could not check commit message for [skip ci]".to_string());
+                }
+            }
+        }
+
+        if mapping.cancel_previous {
+            let previous =
+                db::Queries::get_in_flight_pull_request_triggers(&self.pool, pr.id, mapping.id)
+                    .await?;
+            let reason = format!("Aborted: superseded by {}", short_sha(&trigger.commit_sha));
+            for previous in previous
+                .iter()
+                .filter(|previous| previous.commit_sha != trigger.commit_sha)
+            {
+                let build = build_label(previous);
+                match self.abort_build(mapping, previous, &reason).await {
+                    Ok(()) => notes.push(format!("Aborted previous build {}", build)),
+                    Err(e) => {
+                        warn!(
+                            "Failed to abort previous build: {} {}: {}",
+                            mapping.jenkins_job_name, build, e
+                        );
+                        notes.push(format!("Failed to abort previous build {}", build));
+                    }
+                }
+            }
+        }
+
+        Ok((AutoBuildDecision::Build, notes))
+    }
+
+    /// 큐 대기/진행 중인 빌드 중단
+    ///
+    /// 큐 대기 중이면 큐에서 취소하고, 결과는 `BuildWatcher`가 중단 상태로 기록.
+    /// 중단 사유는 기존 트리거 메시지 뒤에 덧붙임
+    async fn abort_build(
+        &self,
+        mapping: &JenkinsMapping,
+        previous: &BuildTrigger,
+        reason: &str,
+    ) -> Result<()> {
+        let client = self.client_for(mapping)?;
+        match (
+            previous.jenkins_build_url.as_deref(),
+            previous.jenkins_queue_url.as_deref(),
+        ) {
+            (Some(build_url), _) => client.stop_build(build_url).await?,
+            (None, Some(queue_url)) => client.cancel_queue_item(queue_url).await?,
+            (None, None) => return Ok(()),
+        }
+
+        let message = match previous.trigger_message.as_deref() {
+            Some(message) => format!("{}; {}", message, reason),
+            None => reason.to_string(),
+        };
+        db::Queries::set_build_trigger_message(&self.pool, previous.id, &message).await?;
+        info!(
+            "Build aborted: {} {} ({})",
+            mapping.jenkins_job_name, previous.commit_sha, reason
+        );
+        Ok(())
+    }
+
+    /// PR의 현재 head 커밋을 새 시도로 빌드 (코멘트 명령 등 요청에 따른 재빌드)
+    ///
+    /// 자동 빌드 조건(초안, `[skip ci]` 등)은 적용하지 않음
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - 트리거 메시지 (요청자 등)
+    /// * `matched_paths` - 경로 조건에 일치한 변경 파일
+    pub async fn rebuild_pull_request(
+        &self,
+        mapping: &JenkinsMapping,
+        pr: &PullRequest,
+        message: &str,
+        matched_paths: Option<&[String]>,
+    ) -> Result<BuildTrigger> {
+        let trigger = db::Queries::create_retry_build_trigger(
+            &self.pool,
+            pr.id,
+            mapping.id,
+            &pr.head_sha,
+            Some(message),
+        )
+        .await?;
+        let trigger = self.record_matched_paths(trigger, matched_paths).await?;
+        self.execute(mapping, BuildTarget::PullRequest(pr), trigger)
+            .await
+    }
+
+    /// PR의 현재 head 커밋 빌드를 건너뜀
+    ///
+    /// 같은 PR/매핑의 큐 대기/진행 중인 빌드를 중단하고, 건너뛴 트리거를 새 시도로 기록한 뒤
+    /// 커밋 상태를 성공(건너뜀)으로 보고
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - 트리거 메시지 (요청자 등)
+    pub async fn skip_pull_request(
+        &self,
+        mapping: &JenkinsMapping,
+        pr: &PullRequest,
+        message: &str,
+    ) -> Result<BuildTrigger> {
+        let mut messages = vec![message.to_string()];
+        let in_flight =
+            db::Queries::get_in_flight_pull_request_triggers(&self.pool, pr.id, mapping.id).await?;
+        let reason = format!("Aborted ({})", message);
+        for previous in &in_flight {
+            let build = build_label(previous);
+            match self.abort_build(mapping, previous, &reason).await {
+                Ok(()) => messages.push(format!("Aborted build {}", build)),
+                Err(e) => {
+                    warn!(
+                        "Failed to abort build: {} {}: {}",
+                        mapping.jenkins_job_name, build, e
+                    );
+                    messages.push(format!("Failed to abort build {}", build));
+                }
+            }
+        }
+
+        let trigger = db::Queries::create_retry_build_trigger(
+            &self.pool,
+            pr.id,
+            mapping.id,
+            &pr.head_sha,
+            None,
+        )
+        .await?;
+        let trigger = db::Queries::skip_build_trigger(
+            &self.pool,
+            trigger.id,
+            SkipReason::Command,
+            &messages.join("; "),
+        )
+        .await?;
+        self.report_status(mapping, &trigger).await;
+        info!(
+            "Build skipped: {} PR #{} ({}): {:?}",
+            mapping.jenkins_job_name, pr.pr_number, trigger.commit_sha, trigger.trigger_message
+        );
+        Ok(trigger)
+    }
+
+    /// 수동 빌드 트리거 이력 생성
+    ///
+    /// `force`가 true면 이미 트리거된 커밋이라도 시도 번호를 증가시켜 새로 기록
+    ///
+    /// # Returns
+    ///
+    /// `force`가 false이고 이미 같은 커밋이 같은 매핑으로 트리거된 이력이 있으면 None
+    pub async fn create_manual_trigger(
+        &self,
+        mapping: &JenkinsMapping,
+        pr: &PullRequest,
+        force: bool,
+        matched_paths: Option<&[String]>,
+    ) -> Result<Option<BuildTrigger>> {
+        let trigger = if force {
+            db::Queries::create_retry_build_trigger(
+                &self.pool,
+                pr.id,
+                mapping.id,
+                &pr.head_sha,
+                Some("Forced rebuild"),
+            )
+            .await?
+        } else {
+            match db::Queries::create_build_trigger(
+                &self.pool,
+                pr.id,
+                mapping.id,
+                &pr.head_sha,
+                Some("Manually triggered"),
+            )
+            .await?
+            {
+                Some(trigger) => trigger,
+                None => return Ok(None),
+            }
+        };
+        Ok(Some(
+            self.record_matched_paths(trigger, matched_paths).await?,
+        ))
+    }
+
+    /// 생성된 트리거 이력으로 Jenkins 빌드 요청
+    ///
+    /// 큐 아이템을 따라가 실제 빌드 번호와 주소를 확인하면 이력에 반영하고,
+    /// 요청이 실패하면 이력을 실패 상태로 기록
+    pub async fn execute(
+        &self,
+        mapping: &JenkinsMapping,
+        target: BuildTarget<'_>,
+        trigger: BuildTrigger,
+    ) -> Result<BuildTrigger> {
+        self.report_status(mapping, &trigger).await;
+
+        match self.run_trigger(mapping, &target, &trigger).await {
+            Ok(trigger) => {
+                info!(
+                    "Build triggered: {} {} ({}, attempt {}) -> {:?}",
+                    mapping.jenkins_job_name,
+                    target,
+                    trigger.commit_sha,
+                    trigger.attempt,
+                    trigger.jenkins_build_url
+                );
+                self.events.publish(ServerMessageType::BuildTriggered {
+                    repo_id: mapping.repository_id,
+                    pr_id: trigger.pull_request_id,
+                    branch_id: trigger.branch_id,
+                    tag_id: trigger.tag_id,
+                    commit_sha: trigger.commit_sha.clone(),
+                    jenkins_build_number: trigger.jenkins_build_number,
+                    jenkins_build_url: trigger.jenkins_build_url.clone(),
+                });
+                Ok(trigger)
+            }
+            Err(e) => {
+                error!(
+                    "Failed to trigger build: {} {}: {}",
+                    mapping.jenkins_job_name, target, e
+                );
+                let trigger = db::Queries::update_build_trigger_status(
+                    &self.pool,
+                    trigger.id,
+                    TriggerStatus::Failed,
+                    Some(&e.to_string()),
+                )
+                .await?;
+                self.report_status(mapping, &trigger).await;
+                self.events.publish(ServerMessageType::BuildTriggerFailed {
+                    repo_id: mapping.repository_id,
+                    pr_id: trigger.pull_request_id,
+                    branch_id: trigger.branch_id,
+                    tag_id: trigger.tag_id,
+                    commit_sha: trigger.commit_sha.clone(),
+                    error: e.to_string(),
+                });
+                Ok(trigger)
+            }
+        }
+    }
+
+    async fn run_trigger(
+        &self,
+        mapping: &JenkinsMapping,
+        target: &BuildTarget<'_>,
+        trigger: &BuildTrigger,
+    ) -> Result<BuildTrigger> {
+        let client = self.client_for(mapping)?;
+        let parameters = self.build_parameters(mapping, target).await?;
+        let queue_url = client
+            .trigger_build(&mapping.jenkins_job_name, &parameters)
+            .await?;
+        let trigger =
+            db::Queries::set_build_trigger_queued(&self.pool, trigger.id, &queue_url).await?;
+        self.report_status(mapping, &trigger).await;
+
+        match client
+            .wait_for_build(&queue_url, QUEUE_WAIT_TIMEOUT)
+            .await?
+        {
+            Some(build) => {
+                let trigger = db::Queries::set_build_trigger_started(
+                    &self.pool,
+                    trigger.id,
+                    build.number,
+                    &build.url,
+                )
+                .await?;
+                self.report_status(mapping, &trigger).await;
+                Ok(trigger)
+            }
+            None => Ok(trigger),
+        }
+    }
+
+    /// Jenkins 빌드 파라미터
+    ///
+    /// PR/브랜치 빌드는 매핑의 파라미터 템플릿을 대상 값으로 채우고,
+    /// 태그 빌드는 태그 규칙의 파라미터를 그대로 사용
+    async fn build_parameters(
+        &self,
+        mapping: &JenkinsMapping,
+        target: &BuildTarget<'_>,
+    ) -> Result<Vec<(String, String)>> {
+        let repo = db::Queries::get_repository(&self.pool, mapping.repository_id).await?;
+        match target {
+            BuildTarget::PullRequest(pr) => {
+                let source = db::Queries::get_branch(&self.pool, pr.source_branch_id).await?;
+                let target = db::Queries::get_branch(&self.pool, pr.target_branch_id).await?;
+                render_parameters(
+                    mapping,
+                    &TemplateValues {
+                        pr_number: Some(pr.pr_number),
+                        head_sha: &pr.head_sha,
+                        source_branch: source_branch_name(&source),
+                        target_branch: &target.name,
+                        author: pr.author.as_deref(),
+                        repo_owner: &repo.owner,
+                        repo_name: &repo.name,
+                    },
+                )
+            }
+            BuildTarget::Branch(branch) => render_parameters(
+                mapping,
+                &TemplateValues {
+                    pr_number: None,
+                    head_sha: &branch.head_sha,
+                    source_branch: &branch.name,
+                    target_branch: &branch.name,
+                    author: None,
+                    repo_owner: &repo.owner,
+                    repo_name: &repo.name,
+                },
+            ),
+            BuildTarget::Tag { rule, .. } => Ok(rule.build_parameters()),
+        }
+    }
+
+    /// 트리거를 실행한 Jenkins 매핑 (매핑이 삭제되었으면 None)
+    ///
+    /// 트리거에 별도 job이 기록되어 있으면 해당 job으로 바꾼 매핑 반환
+    pub async fn mapping_for(&self, trigger: &BuildTrigger) -> Result<Option<JenkinsMapping>> {
+        let Some(mapping_id) = trigger.jenkins_mapping_id else {
+            return Ok(None);
+        };
+        let mapping = db::Queries::get_jenkins_mapping(&self.pool, mapping_id).await?;
+        Ok(match (mapping, trigger.jenkins_job_name.as_deref()) {
+            (Some(mapping), Some(job_name)) => Some(with_job(&mapping, job_name)),
+            (mapping, _) => mapping,
+        })
+    }
+
+    /// 트리거 상태를 빌드 커밋의 GitHub 커밋 상태로 보고
+    ///
+    /// 보고 실패는 빌드 진행에 영향을 주지 않도록 로그만 남김
+    pub async fn report_status(&self, mapping: &JenkinsMapping, trigger: &BuildTrigger) {
+        if let Err(e) = self.try_report_status(mapping, trigger).await {
+            error!(
+                "Failed to report commit status: {} ({}): {}",
+                trigger.commit_sha, mapping.status_context, e
+            );
+        }
+    }
+
+    async fn try_report_status(
+        &self,
+        mapping: &JenkinsMapping,
+        trigger: &BuildTrigger,
+    ) -> Result<()> {
+        let repo = db::Queries::get_repository(&self.pool, mapping.repository_id).await?;
+        let (state, description) = commit_state_for(trigger);
+        let status = CommitStatus {
+            state,
+            target_url: trigger.jenkins_build_url.clone(),
+            description,
+            context: mapping.status_context.clone(),
+        };
+
+        self.github
+            .create_commit_status(&repo.owner, &repo.name, &trigger.commit_sha, &status)
+            .await?;
+        debug!(
+            "Commit status reported: {} {:?} ({})",
+            trigger.commit_sha, state, mapping.status_context
+        );
+        Ok(())
+    }
+}
+
+/// 초안이라 건너뛴 트리거인지 확인
+fn skipped_as_draft(trigger: &BuildTrigger) -> bool {
+    trigger.trigger_status == TriggerStatus::Skipped.as_str()
+        && trigger.skip_reason.as_deref() == Some(SkipReason::Draft.as_str())
+}
+
+/// 커밋 메시지에 `[skip ci]` (또는 `[ci skip]`)가 있는지 여부
+fn has_skip_ci(message: &str) -> bool {
+    let message = message.to_ascii_lowercase();
+    message.contains("[skip ci]") || message.contains("[ci skip]")
+}
+
+/// 메시지 표시용 짧은 커밋 SHA
+fn short_sha(sha: &str) -> &str {
+    sha.get(..7).unwrap_or(sha)
+}
+
+/// 라벨 변경 트리거 메시지 (예: "Labels added: ready-for-ci; Labels removed: wip")
+fn label_change_message(added: &[String], removed: &[String]) -> String {
+    let mut messages = Vec::new();
+    if !added.is_empty() {
+        messages.push(format!("Labels added: {}", added.join(", ")));
+    }
+    if !removed.is_empty() {
+        messages.push(format!("Labels removed: {}", removed.join(", ")));
+    }
+    messages.join("; ")
+}
+
+/// 메시지 표시용 빌드 이름 (빌드 번호가 없으면 커밋)
+fn build_label(trigger: &BuildTrigger) -> String {
+    trigger
+        .jenkins_build_number
+        .map(|number| format!("#{}", number))
+        .unwrap_or_else(|| format!("for {}", short_sha(&trigger.commit_sha)))
+}
+
+/// 매핑의 파라미터 템플릿을 채운 Jenkins 빌드 파라미터
+fn render_parameters(
+    mapping: &JenkinsMapping,
+    values: &TemplateValues,
+) -> Result<Vec<(String, String)>> {
+    mapping
+        .parameters
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter_map(|(name, template)| Some((name, template.as_str()?)))
+        .map(|(name, template)| Ok((name.clone(), render_template(template, values)?)))
+        .collect()
+}
+
+/// PR source 브랜치 이름
+///
+/// 포크 브랜치는 `owner:branch` 형식으로 저장되어 있으므로 브랜치 이름만 반환
+fn source_branch_name(branch: &Branch) -> &str {
+    branch
+        .name
+        .split_once(':')
+        .map_or(branch.name.as_str(), |(_, name)| name)
+}
+
+/// 저장소 매핑의 Jenkins 서버/커밋 상태 설정으로 다른 job을 빌드하기 위한 매핑
+fn with_job(mapping: &JenkinsMapping, job_name: &str) -> JenkinsMapping {
+    JenkinsMapping {
+        jenkins_job_name: job_name.to_string(),
+        ..mapping.clone()
+    }
+}
+
+/// 트리거 상태에 대응하는 커밋 상태와 설명
+fn commit_state_for(trigger: &BuildTrigger) -> (CommitState, String) {
+    let build = trigger
+        .jenkins_build_number
+        .map(|number| format!("Jenkins build #{}", number))
+        .unwrap_or_else(|| "Jenkins build".to_string());
+
+    match TriggerStatus::parse(&trigger.trigger_status) {
+        Some(TriggerStatus::Pending) => {
+            (CommitState::Pending, "Triggering Jenkins build".to_string())
+        }
+        Some(TriggerStatus::Queued) => (CommitState::Pending, "Jenkins build queued".to_string()),
+        Some(TriggerStatus::Started) => (CommitState::Pending, format!("{} started", build)),
+        Some(TriggerStatus::Running) => (CommitState::Pending, format!("{} running", build)),
+        Some(TriggerStatus::Success) => (CommitState::Success, format!("{} succeeded", build)),
+        Some(TriggerStatus::Failure) => (CommitState::Failure, format!("{} failed", build)),
+        Some(TriggerStatus::Unstable) => (CommitState::Failure, format!("{} is unstable", build)),
+        Some(TriggerStatus::Aborted) => (CommitState::Error, format!("{} aborted", build)),
+        Some(TriggerStatus::Skipped) => (CommitState::Success, "Jenkins build skipped".to_string()),
+        Some(TriggerStatus::Failed) | None => (
+            CommitState::Error,
+            "Failed to trigger Jenkins build".to_string(),
+        ),
+    }
+}
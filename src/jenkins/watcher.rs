@@ -0,0 +1,236 @@
+//! 진행 중인 Jenkins 빌드의 완료 추적
+//!
+//! 큐 대기/진행 중인 트리거를 주기적으로 조회하여 빌드 번호 확정, 진행 상태,
+//! 최종 결과와 소요 시간을 `build_triggers`에 기록하고 완료 이벤트를 전송
+
+use crate::{
+    db::{self, BuildTrigger, JenkinsMapping, TriggerStatus},
+    jenkins::{BuildTriggerService, JenkinsClient},
+    ws::{event_bus::EventBus, ws_message::ServerMessageType},
+};
+use anyhow::Result;
+use sqlx::PgPool;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+/// 진행 중인 빌드 확인 주기
+const WATCH_INTERVAL: Duration = Duration::from_secs(15);
+
+pub struct BuildWatcher {
+    pool: PgPool,
+    triggers: BuildTriggerService,
+    events: EventBus,
+}
+
+impl BuildWatcher {
+    pub fn new(pool: &PgPool, triggers: BuildTriggerService, events: EventBus) -> Self {
+        BuildWatcher {
+            pool: pool.clone(),
+            triggers,
+            events,
+        }
+    }
+
+    pub fn start(self) -> JoinHandle<()> {
+        tokio::spawn(self.run())
+    }
+
+    async fn run(self) {
+        let mut ticker = tokio::time::interval(WATCH_INTERVAL);
+        loop {
+            ticker.tick().await;
+
+            let triggers = match db::Queries::get_in_flight_build_triggers(&self.pool).await {
+                Ok(triggers) => triggers,
+                Err(e) => {
+                    error!("Failed to load in-flight builds: {}", e);
+                    continue;
+                }
+            };
+
+            for trigger in triggers {
+                if let Err(e) = self.check(trigger.clone()).await {
+                    error!("Failed to check build trigger {}: {}", trigger.id, e);
+                }
+            }
+        }
+    }
+
+    async fn check(&self, trigger: BuildTrigger) -> Result<()> {
+        let Some(mapping) = self.triggers.mapping_for(&trigger).await? else {
+            // 매핑이 삭제되면 결과를 조회할 Jenkins를 알 수 없으므로 중단으로 완료 처리
+            return self
+                .close(
+                    None,
+                    &trigger,
+                    TriggerStatus::Aborted,
+                    "Aborted: Jenkins mapping deleted",
+                )
+                .await;
+        };
+        let client = self.triggers.client_for(&mapping)?;
+
+        let Some(trigger) = self
+            .resolve_build_number(&mapping, &client, trigger)
+            .await?
+        else {
+            return Ok(());
+        };
+        let (Some(build_number), Some(build_url)) = (
+            trigger.jenkins_build_number,
+            trigger.jenkins_build_url.as_deref(),
+        ) else {
+            return Ok(());
+        };
+
+        let Some(build) = client.get_build(build_url).await? else {
+            warn!(
+                "Jenkins build not found: {} #{} ({})",
+                mapping.jenkins_job_name, build_number, build_url
+            );
+            return self
+                .close(
+                    Some(&mapping),
+                    &trigger,
+                    TriggerStatus::Failed,
+                    "Failed: Jenkins build not found",
+                )
+                .await;
+        };
+        let result = match build.result.as_deref() {
+            Some(result) if !build.building => result,
+            _ => {
+                if trigger.trigger_status != TriggerStatus::Running.as_str() {
+                    let trigger = db::Queries::update_build_trigger_status(
+                        &self.pool,
+                        trigger.id,
+                        TriggerStatus::Running,
+                        trigger.trigger_message.as_deref(),
+                    )
+                    .await?;
+                    self.triggers.report_status(&mapping, &trigger).await;
+                }
+                return Ok(());
+            }
+        };
+
+        let status = TriggerStatus::from_jenkins_result(result).unwrap_or(TriggerStatus::Failure);
+        let trigger =
+            db::Queries::complete_build_trigger(&self.pool, trigger.id, status, build.duration)
+                .await?;
+        info!(
+            "Build completed: {} #{} -> {}",
+            mapping.jenkins_job_name, build_number, result
+        );
+        self.triggers.report_status(&mapping, &trigger).await;
+        self.notify_completed(mapping.repository_id, &trigger);
+        Ok(())
+    }
+
+    /// 큐 대기 중인 트리거의 빌드 번호 확인
+    ///
+    /// # Returns
+    ///
+    /// 아직 큐 대기 중이거나, 큐에서 취소/만료되어 완료 처리된 경우 None
+    async fn resolve_build_number(
+        &self,
+        mapping: &JenkinsMapping,
+        client: &JenkinsClient,
+        trigger: BuildTrigger,
+    ) -> Result<Option<BuildTrigger>> {
+        if trigger.jenkins_build_number.is_some() {
+            return Ok(Some(trigger));
+        }
+        let Some(queue_url) = trigger.jenkins_queue_url.as_deref() else {
+            return Ok(None);
+        };
+
+        let Some(item) = client.get_queue_item(queue_url).await? else {
+            warn!(
+                "Jenkins queue item expired before build started: {} ({})",
+                mapping.jenkins_job_name, queue_url
+            );
+            self.close(
+                Some(mapping),
+                &trigger,
+                TriggerStatus::Failed,
+                "Failed: Jenkins queue item expired before the build number was recorded",
+            )
+            .await?;
+            return Ok(None);
+        };
+        if item.cancelled {
+            let trigger = db::Queries::complete_build_trigger(
+                &self.pool,
+                trigger.id,
+                TriggerStatus::Aborted,
+                0,
+            )
+            .await?;
+            self.triggers.report_status(mapping, &trigger).await;
+            self.notify_completed(mapping.repository_id, &trigger);
+            return Ok(None);
+        }
+
+        match item.executable {
+            Some(build) => {
+                let trigger = db::Queries::set_build_trigger_started(
+                    &self.pool,
+                    trigger.id,
+                    build.number,
+                    &build.url,
+                )
+                .await?;
+                self.triggers.report_status(mapping, &trigger).await;
+                Ok(Some(trigger))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// 빌드 결과를 확인할 수 없는 트리거를 사유와 함께 완료 처리
+    ///
+    /// 사유는 기존 트리거 메시지 뒤에 덧붙이며, 매핑이 없으면 커밋 상태는 보고하지 않음
+    async fn close(
+        &self,
+        mapping: Option<&JenkinsMapping>,
+        trigger: &BuildTrigger,
+        status: TriggerStatus,
+        reason: &str,
+    ) -> Result<()> {
+        let message = match trigger.trigger_message.as_deref() {
+            Some(message) => format!("{}; {}", message, reason),
+            None => reason.to_string(),
+        };
+        let trigger =
+            db::Queries::close_build_trigger(&self.pool, trigger.id, status, &message).await?;
+        info!("Build trigger {} closed: {}", trigger.id, reason);
+
+        let repo_id = match mapping {
+            Some(mapping) => {
+                self.triggers.report_status(mapping, &trigger).await;
+                Some(mapping.repository_id)
+            }
+            None => db::Queries::get_build_trigger_repository_id(&self.pool, &trigger).await?,
+        };
+        if let Some(repo_id) = repo_id {
+            self.notify_completed(repo_id, &trigger);
+        }
+        Ok(())
+    }
+
+    fn notify_completed(&self, repo_id: i32, trigger: &BuildTrigger) {
+        self.events.publish(ServerMessageType::BuildCompleted {
+            repo_id,
+            pr_id: trigger.pull_request_id,
+            branch_id: trigger.branch_id,
+            tag_id: trigger.tag_id,
+            commit_sha: trigger.commit_sha.clone(),
+            jenkins_build_number: trigger.jenkins_build_number,
+            jenkins_build_url: trigger.jenkins_build_url.clone(),
+            result: trigger.trigger_status.clone(),
+            duration_ms: trigger.build_duration_ms,
+        });
+    }
+}
@@ -1,5 +1,12 @@
+mod auth;
 mod config;
 mod db;
+mod git;
+mod github;
+mod jenkins;
+mod rules;
+mod scheduler;
+mod webhook;
 mod ws;
 use tracing::{debug, error, info};
 use tracing_appender::rolling::{RollingFileAppender, Rotation};
@@ -111,38 +118,113 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
+    // 초기 API 토큰 등록
+    if let Some(token) = config
+        .api_token
+        .as_deref()
+        .filter(|token| !token.is_empty())
+    {
+        db::Queries::ensure_api_token(
+            &pool,
+            "bootstrap",
+            &auth::hash_token(token),
+            db::Role::Admin,
+        )
+        .await?;
+        info!("Bootstrap API token registered");
+    }
+
     let mut github_api_poll_interval = 300;
     let mut sync_refs_interval = 180;
+    let mut webhook_reconcile_interval = 3600;
 
     let system_settings = db::Queries::get_system_settings(&pool).await?;
     debug!("System settings: {:?}", system_settings);
-    if system_settings.get("github_api_poll_interval").is_none() {
-        error!("github_api_poll_interval is not set");
+    if let Some(value) = system_settings.get("github_api_poll_interval") {
+        github_api_poll_interval = value.parse().unwrap();
     } else {
-        github_api_poll_interval = system_settings
-            .get("github_api_poll_interval")
-            .unwrap()
-            .parse()
-            .unwrap();
+        error!("github_api_poll_interval is not set");
     }
 
-    if system_settings.get("sync_refs_interval").is_none() {
+    if let Some(value) = system_settings.get("sync_refs_interval") {
+        sync_refs_interval = value.parse().unwrap();
+    } else {
         error!("sync_refs_interval is not set");
+    }
+
+    if let Some(value) = system_settings.get("webhook_reconcile_interval") {
+        webhook_reconcile_interval = value.parse().unwrap();
     } else {
-        sync_refs_interval = system_settings
-            .get("sync_refs_interval")
-            .unwrap()
-            .parse()
-            .unwrap();
+        error!("webhook_reconcile_interval is not set");
     }
 
-    // TODO: 스케줄러 시작
     debug!("github_api_poll_interval: {}", github_api_poll_interval);
     debug!("sync_refs_interval: {}", sync_refs_interval);
+    debug!("webhook_reconcile_interval: {}", webhook_reconcile_interval);
+
+    // 실시간 이벤트 버스
+    let events = ws::event_bus::EventBus::new();
+
+    // 스케줄러 시작
+    let github_client =
+        github::GithubClient::new(&config.github_api_url, config.github_token.clone())?;
+    let poller = github::PullRequestPoller::new(&pool, github_client.clone(), events.clone());
+    let build_triggers =
+        jenkins::BuildTriggerService::new(&pool, &config, github_client.clone(), events.clone());
+    let comment_commands = jenkins::CommentCommandService::new(
+        &pool,
+        github_client.clone(),
+        build_triggers.clone(),
+    );
+    let (polling_scheduler, scheduler_handle) = scheduler::PollingScheduler::new(
+        &pool,
+        poller.clone(),
+        build_triggers.clone(),
+        comment_commands.clone(),
+        events.clone(),
+        github_api_poll_interval,
+        webhook_reconcile_interval,
+    );
+    polling_scheduler.start();
+
+    // 브랜치/태그 동기화 시작
+    let ref_sync = git::RefSyncService::new(
+        &pool,
+        git::GitRemote::new(&config)?,
+        build_triggers.clone(),
+        events.clone(),
+    );
+    scheduler::RefSyncScheduler::new(&pool, ref_sync, sync_refs_interval).start();
+
+    // 빌드 완료 추적 시작
+    jenkins::BuildWatcher::new(&pool, build_triggers.clone(), events.clone()).start();
+
+    // 웹훅 수신 서버 시작
+    let webhook_processor = webhook::WebhookProcessor::new(
+        &pool,
+        github_client,
+        poller,
+        build_triggers.clone(),
+        comment_commands,
+        events.clone(),
+    );
+    let webhook_server = webhook::WebhookServer::new(webhook_processor.clone());
+    let webhook_addr = config.webhook_addr();
+    tokio::spawn(async move {
+        if let Err(e) = webhook_server.run(webhook_addr).await {
+            error!("Webhook server stopped: {}", e);
+        }
+    });
 
     // TODO: WebSocket 서버 시작
-    let ws_server = ws::ws_server::WsServer::new(&pool);
-    ws_server.run(config.server_port).await;
+    let ws_server = ws::ws_server::WsServer::new(
+        &pool,
+        scheduler_handle,
+        build_triggers,
+        webhook_processor,
+        events,
+    );
+    ws_server.run(&config.server_addr()).await;
 
     info!("Server started. Press Ctrl+C to stop.");
 
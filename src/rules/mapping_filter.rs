@@ -0,0 +1,253 @@
+//! Jenkins 매핑별 트리거 조건 판단
+
+use crate::{db::JenkinsMapping, rules::Pattern};
+use anyhow::Result;
+
+/// GitHub 라벨 이름 최대 길이
+pub const MAX_LABEL_LEN: usize = 50;
+
+/// 조건 판단에 쓰이는 빌드 대상 정보
+#[derive(Debug, Clone, Copy)]
+pub struct BuildContext<'a> {
+    /// PR 대상 브랜치 (브랜치 push는 push된 브랜치)
+    pub target_branch: &'a str,
+    /// PR source 브랜치 (브랜치 push는 push된 브랜치)
+    pub source_branch: &'a str,
+    pub labels: &'a [String],
+    /// 변경 파일 경로 (알 수 없으면 None이며 경로 조건은 검사하지 않음)
+    pub changed_paths: Option<&'a [String]>,
+}
+
+/// 컴파일된 매핑 트리거 조건
+///
+/// 비어 있는 조건은 검사하지 않으며, 지정된 조건은 모두 만족해야 일치
+#[derive(Debug, Clone)]
+pub struct MappingFilter {
+    target_branches: Vec<Pattern>,
+    source_branches: Vec<Pattern>,
+    paths: Vec<Pattern>,
+    excluded_paths: Vec<Pattern>,
+    required_labels: Vec<String>,
+    excluded_labels: Vec<String>,
+}
+
+impl MappingFilter {
+    pub fn new(mapping: &JenkinsMapping) -> Result<Self> {
+        Ok(MappingFilter {
+            target_branches: compile(&mapping.target_branch_patterns)?,
+            source_branches: compile(&mapping.source_branch_patterns)?,
+            paths: compile(&mapping.path_patterns)?,
+            excluded_paths: compile(&mapping.excluded_path_patterns)?,
+            required_labels: mapping.required_labels.clone(),
+            excluded_labels: mapping.excluded_labels.clone(),
+        })
+    }
+
+    /// 경로 조건이 있어 변경 파일 목록이 필요한지 여부
+    pub fn needs_changed_paths(&self) -> bool {
+        !self.paths.is_empty() || !self.excluded_paths.is_empty()
+    }
+
+    /// 경로 조건에 일치하는 변경 파일
+    /// (포함 패턴에 맞고 제외 패턴에 맞지 않는 파일, 포함 패턴이 없으면 제외 패턴만 적용)
+    ///
+    /// 경로 조건이 없거나 변경 파일 목록을 알 수 없으면 None
+    pub fn matched_paths(&self, context: &BuildContext<'_>) -> Option<Vec<String>> {
+        let changed_paths = context.changed_paths?;
+        if !self.needs_changed_paths() {
+            return None;
+        }
+        Some(
+            changed_paths
+                .iter()
+                .filter(|path| matches_any(&self.paths, path))
+                .filter(|path| !self.excluded_paths.iter().any(|p| p.matches(path)))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    pub fn matches(&self, context: &BuildContext<'_>) -> bool {
+        let paths_match = self
+            .matched_paths(context)
+            .is_none_or(|paths| !paths.is_empty());
+        let labels_match = (self.required_labels.is_empty()
+            || contains_any(&self.required_labels, context.labels))
+            && !contains_any(&self.excluded_labels, context.labels);
+
+        matches_any(&self.target_branches, context.target_branch)
+            && matches_any(&self.source_branches, context.source_branch)
+            && labels_match
+            && paths_match
+    }
+
+    /// 라벨 변경으로 라벨 조건이 새로 충족될 수 있는지 여부
+    /// (필요한 라벨이 추가되었거나 제외 라벨이 제거됨)
+    pub fn unblocked_by(&self, added: &[String], removed: &[String]) -> bool {
+        contains_any(&self.required_labels, added) || contains_any(&self.excluded_labels, removed)
+    }
+}
+
+/// 라벨 목록에 `labels` 중 하나라도 있으면 true (대소문자 무시)
+fn contains_any(rule_labels: &[String], labels: &[String]) -> bool {
+    labels.iter().any(|label| {
+        rule_labels
+            .iter()
+            .any(|rule_label| rule_label.eq_ignore_ascii_case(label))
+    })
+}
+
+/// 패턴 목록 컴파일 (저장 전 유효성 검사에도 사용)
+pub fn compile(patterns: &[String]) -> Result<Vec<Pattern>> {
+    patterns
+        .iter()
+        .map(|pattern| Pattern::new(pattern))
+        .collect()
+}
+
+/// 패턴이 없거나 하나라도 일치하면 true
+fn matches_any(patterns: &[Pattern], name: &str) -> bool {
+    patterns.is_empty() || patterns.iter().any(|pattern| pattern.matches(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    fn patterns(values: &[&str]) -> Vec<Pattern> {
+        compile(&strings(values)).unwrap()
+    }
+
+    fn empty_filter() -> MappingFilter {
+        MappingFilter {
+            target_branches: Vec::new(),
+            source_branches: Vec::new(),
+            paths: Vec::new(),
+            excluded_paths: Vec::new(),
+            required_labels: Vec::new(),
+            excluded_labels: Vec::new(),
+        }
+    }
+
+    fn context<'a>(labels: &'a [String], changed_paths: Option<&'a [String]>) -> BuildContext<'a> {
+        BuildContext {
+            target_branch: "main",
+            source_branch: "feature/login",
+            labels,
+            changed_paths,
+        }
+    }
+
+    #[test]
+    fn empty_filter_matches_everything() {
+        let filter = empty_filter();
+        let changed = strings(&["src/main.rs"]);
+        assert!(filter.matches(&context(&[], None)));
+        assert!(filter.matches(&context(&[], Some(&changed))));
+        assert!(!filter.needs_changed_paths());
+        assert_eq!(filter.matched_paths(&context(&[], Some(&changed))), None);
+    }
+
+    #[test]
+    fn branch_patterns() {
+        let filter = MappingFilter {
+            target_branches: patterns(&["main", "release/*"]),
+            source_branches: patterns(&["feature/*"]),
+            ..empty_filter()
+        };
+        assert!(filter.matches(&context(&[], None)));
+
+        let other_target = BuildContext {
+            target_branch: "develop",
+            ..context(&[], None)
+        };
+        assert!(!filter.matches(&other_target));
+
+        let nested_source = BuildContext {
+            source_branch: "feature/login/v2",
+            ..context(&[], None)
+        };
+        assert!(!filter.matches(&nested_source));
+    }
+
+    #[test]
+    fn required_and_excluded_labels_ignore_case() {
+        let filter = MappingFilter {
+            required_labels: strings(&["ci", "full-build"]),
+            excluded_labels: strings(&["wip"]),
+            ..empty_filter()
+        };
+        assert!(filter.matches(&context(&strings(&["CI"]), None)));
+        assert!(filter.matches(&context(&strings(&["docs", "full-build"]), None)));
+        assert!(!filter.matches(&context(&[], None)));
+        assert!(!filter.matches(&context(&strings(&["ci", "WIP"]), None)));
+    }
+
+    #[test]
+    fn matched_paths_applies_include_then_exclude() {
+        let filter = MappingFilter {
+            paths: patterns(&["src/**"]),
+            excluded_paths: patterns(&["**/*.md"]),
+            ..empty_filter()
+        };
+        let changed = strings(&["src/lib.rs", "src/README.md", "docs/guide.md", "Cargo.toml"]);
+        assert_eq!(
+            filter.matched_paths(&context(&[], Some(&changed))),
+            Some(strings(&["src/lib.rs"]))
+        );
+        assert!(filter.matches(&context(&[], Some(&changed))));
+
+        let docs_only = strings(&["src/README.md", "docs/guide.md"]);
+        assert_eq!(
+            filter.matched_paths(&context(&[], Some(&docs_only))),
+            Some(Vec::new())
+        );
+        assert!(!filter.matches(&context(&[], Some(&docs_only))));
+    }
+
+    #[test]
+    fn excluded_paths_only_keeps_other_files() {
+        let filter = MappingFilter {
+            excluded_paths: patterns(&["docs/**"]),
+            ..empty_filter()
+        };
+        assert!(filter.needs_changed_paths());
+
+        let changed = strings(&["docs/index.md", "src/main.rs"]);
+        assert_eq!(
+            filter.matched_paths(&context(&[], Some(&changed))),
+            Some(strings(&["src/main.rs"]))
+        );
+
+        let docs_only = strings(&["docs/index.md"]);
+        assert!(!filter.matches(&context(&[], Some(&docs_only))));
+    }
+
+    #[test]
+    fn unknown_changed_paths_skip_path_patterns() {
+        let filter = MappingFilter {
+            paths: patterns(&["src/**"]),
+            ..empty_filter()
+        };
+        assert_eq!(filter.matched_paths(&context(&[], None)), None);
+        assert!(filter.matches(&context(&[], None)));
+    }
+
+    #[test]
+    fn unblocked_by_label_changes() {
+        let filter = MappingFilter {
+            required_labels: strings(&["ci"]),
+            excluded_labels: strings(&["wip"]),
+            ..empty_filter()
+        };
+        assert!(filter.unblocked_by(&strings(&["CI"]), &[]));
+        assert!(filter.unblocked_by(&[], &strings(&["wip"])));
+        assert!(!filter.unblocked_by(&strings(&["wip"]), &strings(&["ci"])));
+        assert!(!filter.unblocked_by(&strings(&["docs"]), &strings(&["bug"])));
+        assert!(!empty_filter().unblocked_by(&strings(&["ci"]), &strings(&["wip"])));
+    }
+}
@@ -0,0 +1,7 @@
+// 하위 모듈 선언
+mod mapping_filter;
+mod pattern;
+
+// 공개 API
+pub use mapping_filter::*;
+pub use pattern::*;
@@ -0,0 +1,87 @@
+//! 브랜치/태그/경로 이름 glob 패턴
+//!
+//! `*`, `?`는 `/`를 넘지 않고 `**`만 여러 단계와 일치
+//! (예: `release/*`는 `release/1.0`과 일치하지만 `release/1.0/hotfix`와는 불일치)
+
+use anyhow::{Result, anyhow};
+use globset::{GlobBuilder, GlobMatcher};
+
+/// 패턴 최대 길이 (규칙 테이블의 VARCHAR(255))
+pub const MAX_PATTERN_LEN: usize = 255;
+
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    matcher: GlobMatcher,
+}
+
+impl Pattern {
+    /// 패턴 컴파일 (저장 전 유효성 검사에도 사용)
+    pub fn new(pattern: &str) -> Result<Self> {
+        if pattern.is_empty() || pattern.len() > MAX_PATTERN_LEN {
+            return Err(anyhow!(
+                "pattern must be between 1 and {} characters",
+                MAX_PATTERN_LEN
+            ));
+        }
+        let glob = GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()
+            .map_err(|e| anyhow!("invalid pattern {:?}: {}", pattern, e.kind()))?;
+        Ok(Pattern {
+            matcher: glob.compile_matcher(),
+        })
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        self.matcher.is_match(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, name: &str) -> bool {
+        Pattern::new(pattern).unwrap().matches(name)
+    }
+
+    #[test]
+    fn single_star_stays_within_segment() {
+        assert!(matches("release/*", "release/1.0"));
+        assert!(!matches("release/*", "release/1.0/hotfix"));
+        assert!(!matches("*", "feature/login"));
+        assert!(matches("v*", "v1.2.3"));
+    }
+
+    #[test]
+    fn double_star_crosses_segments() {
+        assert!(matches("release/**", "release/1.0"));
+        assert!(matches("release/**", "release/1.0/hotfix"));
+        assert!(matches("docs/**/*.md", "docs/guide/setup/install.md"));
+        assert!(matches("**/*.md", "README.md"));
+        assert!(!matches("docs/**/*.md", "src/docs/readme.md"));
+    }
+
+    #[test]
+    fn question_mark_and_class_match_one_character() {
+        assert!(matches("v?.0", "v1.0"));
+        assert!(!matches("a?b", "a/b"));
+        assert!(matches("v[0-9].*", "v2.x"));
+        assert!(!matches("v[0-9].*", "vx.1"));
+    }
+
+    #[test]
+    fn exact_name_matches_only_itself() {
+        assert!(matches("main", "main"));
+        assert!(!matches("main", "main2"));
+        assert!(!matches("main", "feature/main"));
+    }
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        assert!(Pattern::new("").is_err());
+        assert!(Pattern::new(&"a".repeat(MAX_PATTERN_LEN + 1)).is_err());
+        assert!(Pattern::new("release/[").is_err());
+        assert!(Pattern::new(&"a".repeat(MAX_PATTERN_LEN)).is_ok());
+    }
+}
@@ -0,0 +1,7 @@
+// 하위 모듈 선언
+mod polling_scheduler;
+mod ref_sync_scheduler;
+
+// 공개 API
+pub use polling_scheduler::*;
+pub use ref_sync_scheduler::*;
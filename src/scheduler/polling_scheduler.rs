@@ -0,0 +1,277 @@
+//! 저장소별 PR 폴링 스케줄러
+//!
+//! 활성 저장소마다 독립된 폴링 태스크를 실행하며, WebSocket API로 저장소가
+//! 추가/수정/삭제되면 `SchedulerHandle`을 통해 재시작 없이 즉시 반영
+//!
+//! 웹훅을 사용하는 저장소는 누락된 이벤트를 보정하는 용도로만 느린 주기로 폴링
+
+use crate::{
+    db::{self, Repository},
+    github::PullRequestPoller,
+    jenkins::{BuildTriggerService, CommentCommandService},
+    ws::{event_bus::EventBus, ws_message::ServerMessageType},
+};
+use sqlx::PgPool;
+use std::{collections::HashMap, time::Duration};
+use tokio::{sync::mpsc, task::JoinHandle};
+use tracing::{debug, error, info};
+
+/// 폴링 주기 지터 비율 (±10%)
+const JITTER_RATIO: f64 = 0.1;
+
+/// 스케줄러 제어 명령
+#[derive(Debug, Clone)]
+pub enum SchedulerCommand {
+    /// 저장소 추가/수정 (활성화 여부, 폴링 주기 재적용)
+    RepositoryChanged(i32),
+
+    /// 저장소 삭제
+    RepositoryRemoved(i32),
+
+    /// 기본 폴링 주기 변경 (`github_api_poll_interval`)
+    DefaultIntervalChanged(u64),
+
+    /// 웹훅 사용 저장소의 보정 폴링 주기 변경 (`webhook_reconcile_interval`)
+    ReconcileIntervalChanged(u64),
+}
+
+/// 스케줄러에 저장소 변경을 알리기 위한 핸들
+#[derive(Debug, Clone)]
+pub struct SchedulerHandle {
+    tx: mpsc::UnboundedSender<SchedulerCommand>,
+}
+
+impl SchedulerHandle {
+    pub fn repository_changed(&self, repo_id: i32) {
+        self.send(SchedulerCommand::RepositoryChanged(repo_id));
+    }
+
+    pub fn repository_removed(&self, repo_id: i32) {
+        self.send(SchedulerCommand::RepositoryRemoved(repo_id));
+    }
+
+    pub fn default_interval_changed(&self, interval_secs: u64) {
+        self.send(SchedulerCommand::DefaultIntervalChanged(interval_secs));
+    }
+
+    pub fn reconcile_interval_changed(&self, interval_secs: u64) {
+        self.send(SchedulerCommand::ReconcileIntervalChanged(interval_secs));
+    }
+
+    fn send(&self, command: SchedulerCommand) {
+        if let Err(e) = self.tx.send(command) {
+            error!("Scheduler is not running: {:?}", e.0);
+        }
+    }
+}
+
+/// 실행 중인 저장소 폴링 태스크
+struct PollingTask {
+    interval_secs: u64,
+    handle: JoinHandle<()>,
+}
+
+/// 저장소 폴링 태스크가 공유하는 자원
+#[derive(Clone)]
+struct PollingWorker {
+    pool: PgPool,
+    poller: PullRequestPoller,
+    triggers: BuildTriggerService,
+    comments: CommentCommandService,
+    events: EventBus,
+}
+
+pub struct PollingScheduler {
+    worker: PollingWorker,
+    /// 저장소에 폴링 주기가 없을 때 사용 (`github_api_poll_interval`)
+    default_interval_secs: u64,
+    /// 웹훅 사용 저장소의 최소 폴링 주기 (`webhook_reconcile_interval`)
+    reconcile_interval_secs: u64,
+    tasks: HashMap<i32, PollingTask>,
+    rx: mpsc::UnboundedReceiver<SchedulerCommand>,
+}
+
+impl PollingScheduler {
+    pub fn new(
+        pool: &PgPool,
+        poller: PullRequestPoller,
+        triggers: BuildTriggerService,
+        comments: CommentCommandService,
+        events: EventBus,
+        default_interval_secs: u64,
+        reconcile_interval_secs: u64,
+    ) -> (Self, SchedulerHandle) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let scheduler = PollingScheduler {
+            worker: PollingWorker {
+                pool: pool.clone(),
+                poller,
+                triggers,
+                comments,
+                events,
+            },
+            default_interval_secs,
+            reconcile_interval_secs,
+            tasks: HashMap::new(),
+            rx,
+        };
+        (scheduler, SchedulerHandle { tx })
+    }
+
+    pub fn start(self) -> JoinHandle<()> {
+        tokio::spawn(self.run())
+    }
+
+    async fn run(mut self) {
+        self.apply_all().await;
+        info!(
+            "Polling scheduler started: {} repositories",
+            self.tasks.len()
+        );
+
+        while let Some(command) = self.rx.recv().await {
+            debug!("Scheduler command: {:?}", command);
+            match command {
+                SchedulerCommand::RepositoryChanged(repo_id) => {
+                    match db::Queries::get_repository(&self.worker.pool, repo_id).await {
+                        Ok(repo) => self.apply(repo),
+                        Err(e) => error!("Failed to load repository {}: {}", repo_id, e),
+                    }
+                }
+                SchedulerCommand::RepositoryRemoved(repo_id) => self.stop(repo_id),
+                SchedulerCommand::DefaultIntervalChanged(interval_secs) => {
+                    self.default_interval_secs = interval_secs;
+                    self.apply_all().await;
+                }
+                SchedulerCommand::ReconcileIntervalChanged(interval_secs) => {
+                    self.reconcile_interval_secs = interval_secs;
+                    self.apply_all().await;
+                }
+            }
+        }
+    }
+
+    /// 전체 저장소 목록을 다시 읽어 폴링 태스크에 반영
+    async fn apply_all(&mut self) {
+        match db::Queries::get_repositories(&self.worker.pool).await {
+            Ok(repositories) => {
+                for repo in repositories {
+                    self.apply(repo);
+                }
+            }
+            Err(e) => error!("Failed to load repositories: {}", e),
+        }
+    }
+
+    /// 저장소 상태에 맞춰 폴링 태스크 시작/재시작/중지
+    fn apply(&mut self, repo: Repository) {
+        if !repo.is_active {
+            self.stop(repo.id);
+            return;
+        }
+
+        let interval_secs = self.interval_for(&repo);
+        if let Some(task) = self.tasks.get(&repo.id)
+            && task.interval_secs == interval_secs
+            && !task.handle.is_finished()
+        {
+            return;
+        }
+        self.stop(repo.id);
+
+        info!(
+            "Polling started: {}/{} every {}s",
+            repo.owner, repo.name, interval_secs
+        );
+        let handle = tokio::spawn(self.worker.clone().poll_loop(repo.id, interval_secs));
+        self.tasks.insert(
+            repo.id,
+            PollingTask {
+                interval_secs,
+                handle,
+            },
+        );
+    }
+
+    fn stop(&mut self, repo_id: i32) {
+        if let Some(task) = self.tasks.remove(&repo_id) {
+            task.handle.abort();
+            info!("Polling stopped: repository {}", repo_id);
+        }
+    }
+
+    fn interval_for(&self, repo: &Repository) -> u64 {
+        let interval_secs = repo
+            .poll_interval_seconds
+            .filter(|secs| *secs > 0)
+            .map(|secs| secs as u64)
+            .unwrap_or(self.default_interval_secs);
+        if repo.webhook_enabled() {
+            interval_secs.max(self.reconcile_interval_secs)
+        } else {
+            interval_secs
+        }
+        .max(1)
+    }
+}
+
+impl PollingWorker {
+    /// 저장소 폴링 루프
+    ///
+    /// 첫 폴링 시점을 주기 내에서 무작위로 분산시켜 저장소들이 동시에 GitHub를 호출하지 않도록 함.
+    /// 주기가 그대로인 저장소 수정은 태스크를 재시작하지 않으므로 매 폴링마다 저장소를 다시 읽음
+    async fn poll_loop(self, repo_id: i32, interval_secs: u64) {
+        let initial_delay = rand::random_range(0..interval_secs);
+        tokio::time::sleep(Duration::from_secs(initial_delay)).await;
+
+        loop {
+            match db::Queries::get_repository(&self.pool, repo_id).await {
+                Ok(repo) => self.poll_once(&repo).await,
+                Err(e) => error!("Failed to load repository {}: {}", repo_id, e),
+            }
+            tokio::time::sleep(jittered(interval_secs)).await;
+        }
+    }
+
+    async fn poll_once(&self, repo: &Repository) {
+        match self.poller.poll_repository(repo).await {
+            Ok(changes) => {
+                if let Err(e) = self
+                    .triggers
+                    .handle_pull_request_changes(repo, &changes)
+                    .await
+                {
+                    error!(
+                        "Failed to trigger builds for {}/{}: {}",
+                        repo.owner, repo.name, e
+                    );
+                }
+            }
+            Err(e) => error!("Failed to poll {}/{}: {}", repo.owner, repo.name, e),
+        }
+
+        if let Err(e) = self.comments.poll_comments(repo).await {
+            error!(
+                "Failed to poll comments {}/{}: {}",
+                repo.owner, repo.name, e
+            );
+        }
+
+        match db::Queries::create_polling_history(&self.pool, repo.id).await {
+            Ok(history) => self.events.publish(ServerMessageType::PollingCompleted {
+                repo_id: repo.id,
+                polled_at: history
+                    .polled_at
+                    .format("%Y-%m-%dT%H:%M:%S%.6f")
+                    .to_string(),
+            }),
+            Err(e) => error!("Failed to record polling history: {}", e),
+        }
+    }
+}
+
+/// 주기에 ±`JITTER_RATIO` 범위의 무작위 편차 적용
+fn jittered(interval_secs: u64) -> Duration {
+    let factor = 1.0 + rand::random_range(-JITTER_RATIO..=JITTER_RATIO);
+    Duration::from_secs_f64(interval_secs as f64 * factor)
+}
@@ -0,0 +1,70 @@
+//! 브랜치/태그 동기화 스케줄러
+//!
+//! `sync_refs_interval` 주기로 활성 저장소 전체의 ref를 순차 동기화하며,
+//! 주기 설정은 매 실행 후 다시 읽어 재시작 없이 다음 주기부터 반영
+
+use crate::{db, git::RefSyncService};
+use sqlx::PgPool;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+/// 주기 설정 키
+const SYNC_REFS_INTERVAL_KEY: &str = "sync_refs_interval";
+
+pub struct RefSyncScheduler {
+    pool: PgPool,
+    service: RefSyncService,
+    /// 설정을 읽지 못했을 때 사용할 주기
+    default_interval_secs: u64,
+}
+
+impl RefSyncScheduler {
+    pub fn new(pool: &PgPool, service: RefSyncService, default_interval_secs: u64) -> Self {
+        RefSyncScheduler {
+            pool: pool.clone(),
+            service,
+            default_interval_secs,
+        }
+    }
+
+    pub fn start(self) -> JoinHandle<()> {
+        tokio::spawn(self.run())
+    }
+
+    async fn run(self) {
+        info!("Ref sync scheduler started");
+        loop {
+            self.sync_all().await;
+            tokio::time::sleep(Duration::from_secs(self.interval_secs().await)).await;
+        }
+    }
+
+    async fn sync_all(&self) {
+        let repositories = match db::Queries::get_repositories(&self.pool).await {
+            Ok(repositories) => repositories,
+            Err(e) => {
+                error!("Failed to load repositories: {}", e);
+                return;
+            }
+        };
+
+        for repo in repositories.iter().filter(|repo| repo.is_active) {
+            if let Err(e) = self.service.sync_repository(repo).await {
+                error!("Failed to sync refs {}/{}: {}", repo.owner, repo.name, e);
+            }
+        }
+    }
+
+    async fn interval_secs(&self) -> u64 {
+        match db::Queries::get_system_setting(&self.pool, SYNC_REFS_INTERVAL_KEY).await {
+            Ok(Some(setting)) => setting.value.parse().unwrap_or(self.default_interval_secs),
+            Ok(None) => self.default_interval_secs,
+            Err(e) => {
+                error!("Failed to load {}: {}", SYNC_REFS_INTERVAL_KEY, e);
+                self.default_interval_secs
+            }
+        }
+        .max(1)
+    }
+}
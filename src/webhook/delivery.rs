@@ -0,0 +1,87 @@
+//! 웹훅 전송(delivery) 처리 결과
+//!
+//! HTTP 수신과 저장된 전송 기록 재처리가 같은 결과 형식을 사용하며,
+//! 모든 전송은 결과와 함께 `webhook_deliveries`에 기록
+
+use crate::{db::WebhookDelivery, webhook::WebhookOutcome};
+use serde_json::Value;
+
+/// 서명 헤더 이름 (헤더 JSON의 키는 소문자)
+pub const SIGNATURE_HEADER: &str = "x-hub-signature-256";
+
+/// 처리할 웹훅 전송
+pub struct InboundDelivery<'a> {
+    /// `X-GitHub-Delivery` 헤더 값
+    pub delivery_guid: Option<&'a str>,
+    /// `X-GitHub-Event` 헤더 값
+    pub event: Option<&'a str>,
+    /// 요청 헤더 (`{"이름": "값"}`)
+    pub headers: &'a Value,
+    pub body: &'a str,
+    /// 재처리인 경우 원본 전송 기록 ID
+    pub replay_of: Option<i32>,
+}
+
+impl InboundDelivery<'_> {
+    pub fn signature(&self) -> Option<&str> {
+        self.headers.get(SIGNATURE_HEADER).and_then(Value::as_str)
+    }
+}
+
+/// 처리 전에 거부된 사유
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookRejection {
+    MissingEvent,
+    MissingRepository,
+    UnknownRepository,
+    WebhookDisabled,
+    InvalidSignature,
+}
+
+impl WebhookRejection {
+    pub fn message(&self) -> &'static str {
+        match self {
+            WebhookRejection::MissingEvent => "Missing X-GitHub-Event",
+            WebhookRejection::MissingRepository => "Missing repository",
+            WebhookRejection::UnknownRepository => "Repository not found",
+            WebhookRejection::WebhookDisabled => "Webhook not enabled",
+            WebhookRejection::InvalidSignature => "Invalid signature",
+        }
+    }
+}
+
+/// 전송 처리 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeliveryOutcome {
+    Handled(WebhookOutcome),
+    Rejected(WebhookRejection),
+    /// 처리 중 에러 (GitHub 재전송 대상)
+    Failed(String),
+}
+
+impl DeliveryOutcome {
+    /// `webhook_deliveries.outcome` 값
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeliveryOutcome::Handled(outcome) => outcome.as_str(),
+            DeliveryOutcome::Rejected(_) => "rejected",
+            DeliveryOutcome::Failed(_) => "failed",
+        }
+    }
+
+    /// `webhook_deliveries.error_message` 값
+    pub fn error_message(&self) -> Option<&str> {
+        match self {
+            DeliveryOutcome::Handled(_) => None,
+            DeliveryOutcome::Rejected(rejection) => Some(rejection.message()),
+            DeliveryOutcome::Failed(error) => Some(error),
+        }
+    }
+}
+
+/// 전송 처리 결과와 저장된 기록
+pub struct WebhookReceipt {
+    pub outcome: DeliveryOutcome,
+    /// 기록 저장에 실패하면 None
+    pub delivery: Option<WebhookDelivery>,
+}
@@ -0,0 +1,13 @@
+// 하위 모듈 선언
+mod delivery;
+mod models;
+mod processor;
+mod server;
+mod signature;
+
+// 공개 API
+pub use delivery::*;
+pub use models::*;
+pub use processor::*;
+pub use server::*;
+pub use signature::*;
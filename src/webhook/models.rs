@@ -0,0 +1,103 @@
+//! GitHub 웹훅 페이로드 모델
+//!
+//! 처리에 필요한 필드만 정의하며 나머지 필드는 무시
+
+use crate::github::{GithubIssueComment, GithubPullRequest};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// 모든 이벤트에 공통으로 포함되는 저장소 정보
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookEnvelope {
+    pub repository: Option<WebhookRepository>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookRepository {
+    /// `owner/name` 형식
+    pub full_name: String,
+}
+
+impl WebhookRepository {
+    /// `(owner, name)` 분리
+    pub fn owner_and_name(&self) -> Option<(&str, &str)> {
+        self.full_name.split_once('/')
+    }
+}
+
+/// `pull_request` 이벤트
+#[derive(Debug, Clone, Deserialize)]
+pub struct PullRequestEvent {
+    /// "opened", "synchronize", "closed", "reopened", "edited" 등
+    pub action: String,
+    pub pull_request: GithubPullRequest,
+}
+
+/// `issue_comment` 이벤트의 이슈 정보
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookIssue {
+    pub number: i32,
+    /// PR 코멘트일 때만 포함
+    #[serde(default)]
+    pub pull_request: Option<Value>,
+}
+
+/// `issue_comment` 이벤트
+#[derive(Debug, Clone, Deserialize)]
+pub struct IssueCommentEvent {
+    /// "created", "edited", "deleted"
+    pub action: String,
+    pub issue: WebhookIssue,
+    pub comment: GithubIssueComment,
+}
+
+/// `push` 이벤트
+#[derive(Debug, Clone, Deserialize)]
+pub struct PushEvent {
+    /// `refs/heads/{branch}` 또는 `refs/tags/{tag}`
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+    /// push 이후 커밋 SHA (삭제 시 0으로 채워진 값)
+    pub after: String,
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+/// `create` / `delete` 이벤트
+#[derive(Debug, Clone, Deserialize)]
+pub struct RefEvent {
+    /// 브랜치 또는 태그 이름 (`refs/` 접두사 없음)
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+    /// "branch" 또는 "tag"
+    pub ref_type: String,
+}
+
+/// 브랜치/태그 구분
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefKind {
+    Branch,
+    Tag,
+}
+
+impl RefKind {
+    /// `create` / `delete` 이벤트의 `ref_type` 값 변환
+    pub fn from_ref_type(ref_type: &str) -> Option<Self> {
+        match ref_type {
+            "branch" => Some(RefKind::Branch),
+            "tag" => Some(RefKind::Tag),
+            _ => None,
+        }
+    }
+
+    /// `refs/heads/...` / `refs/tags/...` 전체 ref를 종류와 이름으로 분리
+    pub fn split_ref(full_ref: &str) -> Option<(Self, &str)> {
+        if let Some(name) = full_ref.strip_prefix("refs/heads/") {
+            Some((RefKind::Branch, name))
+        } else {
+            full_ref
+                .strip_prefix("refs/tags/")
+                .map(|name| (RefKind::Tag, name))
+        }
+    }
+}
@@ -0,0 +1,371 @@
+//! GitHub 웹훅 이벤트 처리
+//!
+//! 폴링과 같은 경로(`PullRequestPoller`, `BuildTriggerService`)로 DB에 반영하고 빌드를 트리거
+
+use crate::{
+    db::{self, Repository, WebhookDeliveryEntry},
+    github::{GithubClient, PullRequestPoller},
+    jenkins::{BuildTriggerService, CommentCommandService},
+    webhook::{
+        DeliveryOutcome, InboundDelivery, IssueCommentEvent, PullRequestEvent, PushEvent, RefEvent,
+        RefKind, WebhookEnvelope, WebhookReceipt, WebhookRejection, verify_signature,
+    },
+    ws::{event_bus::EventBus, ws_message::ServerMessageType},
+};
+use anyhow::Result;
+use serde::de::DeserializeOwned;
+use sqlx::PgPool;
+use tracing::{debug, error, info, warn};
+
+/// 웹훅 처리 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookOutcome {
+    /// DB 반영 또는 빌드 트리거 수행
+    Processed,
+    /// 처리 대상이 아닌 이벤트
+    Ignored,
+}
+
+impl WebhookOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookOutcome::Processed => "processed",
+            WebhookOutcome::Ignored => "ignored",
+        }
+    }
+}
+
+/// ref 변경 결과
+enum RefChange {
+    Added,
+    Updated,
+    Deleted,
+    Unchanged,
+}
+
+#[derive(Clone)]
+pub struct WebhookProcessor {
+    pool: PgPool,
+    client: GithubClient,
+    poller: PullRequestPoller,
+    triggers: BuildTriggerService,
+    comments: CommentCommandService,
+    events: EventBus,
+}
+
+impl WebhookProcessor {
+    pub fn new(
+        pool: &PgPool,
+        client: GithubClient,
+        poller: PullRequestPoller,
+        triggers: BuildTriggerService,
+        comments: CommentCommandService,
+        events: EventBus,
+    ) -> Self {
+        WebhookProcessor {
+            pool: pool.clone(),
+            client,
+            poller,
+            triggers,
+            comments,
+            events,
+        }
+    }
+
+    /// 웹훅 전송 처리 후 결과를 `webhook_deliveries`에 기록
+    ///
+    /// 저장소 조회, 서명 검증, 이벤트 처리 순으로 진행하며 재처리도 같은 경로로 수행
+    /// (재처리 시 저장된 서명을 현재 비밀값으로 다시 검증)
+    pub async fn receive(&self, inbound: &InboundDelivery<'_>) -> WebhookReceipt {
+        let (repository_id, signature_valid, outcome) = self.verify_and_process(inbound).await;
+        match &outcome {
+            DeliveryOutcome::Handled(handled) => info!(
+                "Webhook {} {}: repository {:?} ({})",
+                inbound.event.unwrap_or_default(),
+                handled.as_str(),
+                repository_id,
+                inbound.delivery_guid.unwrap_or_default()
+            ),
+            DeliveryOutcome::Rejected(rejection) => warn!(
+                "Webhook rejected: {} ({})",
+                rejection.message(),
+                inbound.delivery_guid.unwrap_or_default()
+            ),
+            DeliveryOutcome::Failed(e) => error!(
+                "Failed to process webhook {}: repository {:?} ({}): {}",
+                inbound.event.unwrap_or_default(),
+                repository_id,
+                inbound.delivery_guid.unwrap_or_default(),
+                e
+            ),
+        }
+
+        let entry = WebhookDeliveryEntry {
+            delivery_guid: inbound.delivery_guid,
+            event: inbound.event,
+            repository_id,
+            headers: inbound.headers,
+            body: inbound.body,
+            signature_valid,
+            outcome: outcome.as_str(),
+            error_message: outcome.error_message(),
+            replay_of: inbound.replay_of,
+        };
+        let delivery = match db::Queries::create_webhook_delivery(&self.pool, &entry).await {
+            Ok(delivery) => Some(delivery),
+            Err(e) => {
+                error!("Failed to record webhook delivery: {}", e);
+                None
+            }
+        };
+
+        WebhookReceipt { outcome, delivery }
+    }
+
+    /// # Returns
+    ///
+    /// (저장소 ID, 서명 검증 결과, 처리 결과)
+    async fn verify_and_process(
+        &self,
+        inbound: &InboundDelivery<'_>,
+    ) -> (Option<i32>, Option<bool>, DeliveryOutcome) {
+        let Some(event) = inbound.event else {
+            return (
+                None,
+                None,
+                DeliveryOutcome::Rejected(WebhookRejection::MissingEvent),
+            );
+        };
+
+        let Some((owner, name)) = serde_json::from_str::<WebhookEnvelope>(inbound.body)
+            .ok()
+            .and_then(|envelope| envelope.repository)
+            .and_then(|repository| {
+                repository
+                    .owner_and_name()
+                    .map(|(owner, name)| (owner.to_string(), name.to_string()))
+            })
+        else {
+            return (
+                None,
+                None,
+                DeliveryOutcome::Rejected(WebhookRejection::MissingRepository),
+            );
+        };
+
+        let repo = match db::Queries::get_repository_by_name(&self.pool, &owner, &name).await {
+            Ok(Some(repo)) => repo,
+            Ok(None) => {
+                return (
+                    None,
+                    None,
+                    DeliveryOutcome::Rejected(WebhookRejection::UnknownRepository),
+                );
+            }
+            Err(e) => return (None, None, DeliveryOutcome::Failed(e.to_string())),
+        };
+
+        let Some(secret) = repo.webhook_secret.as_deref() else {
+            return (
+                Some(repo.id),
+                None,
+                DeliveryOutcome::Rejected(WebhookRejection::WebhookDisabled),
+            );
+        };
+        let signature = inbound.signature().unwrap_or_default();
+        if !verify_signature(secret, signature, inbound.body.as_bytes()) {
+            return (
+                Some(repo.id),
+                Some(false),
+                DeliveryOutcome::Rejected(WebhookRejection::InvalidSignature),
+            );
+        }
+
+        let outcome = match self.process(&repo, event, inbound.body.as_bytes()).await {
+            Ok(handled) => DeliveryOutcome::Handled(handled),
+            Err(e) => DeliveryOutcome::Failed(e.to_string()),
+        };
+        (Some(repo.id), Some(true), outcome)
+    }
+
+    /// 서명 검증을 통과한 웹훅 이벤트 처리
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - 이벤트가 속한 저장소
+    /// * `event` - `X-GitHub-Event` 헤더 값
+    /// * `body` - 요청 본문
+    async fn process(&self, repo: &Repository, event: &str, body: &[u8]) -> Result<WebhookOutcome> {
+        if !repo.is_active {
+            debug!("Webhook ignored for inactive repository: {}", repo.id);
+            return Ok(WebhookOutcome::Ignored);
+        }
+
+        match event {
+            "pull_request" => self.handle_pull_request(repo, parse(body)?).await,
+            "issue_comment" => self.handle_issue_comment(repo, parse(body)?).await,
+            "push" => self.handle_push(repo, parse(body)?).await,
+            "create" => self.handle_create(repo, parse(body)?).await,
+            "delete" => self.handle_delete(repo, parse(body)?).await,
+            // ping 등 처리 대상이 아닌 이벤트
+            _ => Ok(WebhookOutcome::Ignored),
+        }
+    }
+
+    async fn handle_pull_request(
+        &self,
+        repo: &Repository,
+        event: PullRequestEvent,
+    ) -> Result<WebhookOutcome> {
+        info!(
+            "Webhook pull_request {}: {}/{}#{}",
+            event.action, repo.owner, repo.name, event.pull_request.number
+        );
+        let Some(change) = self
+            .poller
+            .apply_pull_request(repo, &event.pull_request)
+            .await?
+        else {
+            return Ok(WebhookOutcome::Ignored);
+        };
+
+        self.triggers
+            .handle_pull_request_changes(repo, &[change])
+            .await?;
+        Ok(WebhookOutcome::Processed)
+    }
+
+    /// 새 PR 코멘트의 명령 처리 (이슈 코멘트와 수정/삭제는 무시)
+    async fn handle_issue_comment(
+        &self,
+        repo: &Repository,
+        event: IssueCommentEvent,
+    ) -> Result<WebhookOutcome> {
+        if event.action != "created" || event.issue.pull_request.is_none() {
+            return Ok(WebhookOutcome::Ignored);
+        }
+        if self
+            .comments
+            .handle_comment(repo, event.issue.number, &event.comment)
+            .await?
+        {
+            Ok(WebhookOutcome::Processed)
+        } else {
+            Ok(WebhookOutcome::Ignored)
+        }
+    }
+
+    async fn handle_push(&self, repo: &Repository, event: PushEvent) -> Result<WebhookOutcome> {
+        let Some((kind, name)) = RefKind::split_ref(&event.ref_name) else {
+            return Ok(WebhookOutcome::Ignored);
+        };
+        let sha = (!event.deleted).then_some(event.after.as_str());
+        self.apply_ref(repo, kind, name, sha).await
+    }
+
+    async fn handle_create(&self, repo: &Repository, event: RefEvent) -> Result<WebhookOutcome> {
+        let Some(kind) = RefKind::from_ref_type(&event.ref_type) else {
+            return Ok(WebhookOutcome::Ignored);
+        };
+        // create 이벤트에는 커밋 SHA가 없어 조회 필요
+        let sha = self
+            .client
+            .get_commit_sha(&repo.owner, &repo.name, &event.ref_name)
+            .await?;
+        self.apply_ref(repo, kind, &event.ref_name, Some(&sha))
+            .await
+    }
+
+    async fn handle_delete(&self, repo: &Repository, event: RefEvent) -> Result<WebhookOutcome> {
+        let Some(kind) = RefKind::from_ref_type(&event.ref_type) else {
+            return Ok(WebhookOutcome::Ignored);
+        };
+        self.apply_ref(repo, kind, &event.ref_name, None).await
+    }
+
+    /// 브랜치/태그 행 갱신 후 동기화 이벤트 발행
+    ///
+    /// # Arguments
+    ///
+    /// * `sha` - ref가 가리키는 커밋 (삭제된 ref는 None)
+    async fn apply_ref(
+        &self,
+        repo: &Repository,
+        kind: RefKind,
+        name: &str,
+        sha: Option<&str>,
+    ) -> Result<WebhookOutcome> {
+        let change = match (kind, sha) {
+            (RefKind::Branch, Some(sha)) => {
+                let (branch, inserted) =
+                    db::Queries::upsert_branch(&self.pool, repo.id, name, sha).await?;
+                self.triggers.handle_branch_update(repo, &branch).await?;
+                if inserted {
+                    RefChange::Added
+                } else {
+                    RefChange::Updated
+                }
+            }
+            (RefKind::Tag, Some(sha)) => {
+                let (tag, inserted) =
+                    db::Queries::upsert_tag(&self.pool, repo.id, name, sha).await?;
+                self.triggers.handle_tag_update(repo, &tag).await?;
+                if inserted {
+                    RefChange::Added
+                } else {
+                    RefChange::Updated
+                }
+            }
+            (RefKind::Branch, None) => {
+                if db::Queries::delete_branch(&self.pool, repo.id, name).await? {
+                    RefChange::Deleted
+                } else {
+                    RefChange::Unchanged
+                }
+            }
+            (RefKind::Tag, None) => {
+                if db::Queries::delete_tag(&self.pool, repo.id, name).await? {
+                    RefChange::Deleted
+                } else {
+                    RefChange::Unchanged
+                }
+            }
+        };
+
+        let (added, updated, deleted) = match change {
+            RefChange::Added => (1, 0, 0),
+            RefChange::Updated => (0, 1, 0),
+            RefChange::Deleted => (0, 0, 1),
+            RefChange::Unchanged => return Ok(WebhookOutcome::Ignored),
+        };
+        info!(
+            "Webhook {:?} {}: {}/{} ({})",
+            kind,
+            name,
+            repo.owner,
+            repo.name,
+            sha.unwrap_or("deleted")
+        );
+
+        let event = match kind {
+            RefKind::Branch => ServerMessageType::BranchesSynced {
+                repo_id: repo.id,
+                added,
+                updated,
+                deleted,
+            },
+            RefKind::Tag => ServerMessageType::TagsSynced {
+                repo_id: repo.id,
+                added,
+                updated,
+                deleted,
+            },
+        };
+        self.events.publish(event);
+        Ok(WebhookOutcome::Processed)
+    }
+}
+
+fn parse<T: DeserializeOwned>(body: &[u8]) -> Result<T> {
+    Ok(serde_json::from_slice(body)?)
+}
@@ -0,0 +1,92 @@
+//! GitHub 웹훅 수신 HTTP 서버
+//!
+//! `POST /webhooks/github`로 이벤트를 받아 저장소별 비밀값으로 서명을 검증한 뒤 처리
+
+use crate::webhook::{DeliveryOutcome, InboundDelivery, WebhookProcessor, WebhookRejection};
+use anyhow::Result;
+use axum::{
+    Router,
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::post,
+};
+use serde_json::{Map, Value};
+use tokio::net::TcpListener;
+use tracing::info;
+
+/// 웹훅 수신 경로
+const WEBHOOK_PATH: &str = "/webhooks/github";
+
+#[derive(Clone)]
+pub struct WebhookServer {
+    processor: WebhookProcessor,
+}
+
+impl WebhookServer {
+    pub fn new(processor: WebhookProcessor) -> Self {
+        WebhookServer { processor }
+    }
+
+    pub async fn run(self, addr: String) -> Result<()> {
+        info!("Webhook Server Start: {}{}", addr, WEBHOOK_PATH);
+        let listener = TcpListener::bind(&addr).await?;
+        let app = Router::new()
+            .route(WEBHOOK_PATH, post(receive_webhook))
+            .with_state(self);
+        axum::serve(listener, app).await?;
+        Ok(())
+    }
+}
+
+/// 웹훅 요청 처리
+///
+/// 처리 중 에러는 GitHub가 재전송할 수 있도록 500으로 응답
+async fn receive_webhook(
+    State(server): State<WebhookServer>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> (StatusCode, String) {
+    let header_values: Map<String, Value> = headers
+        .iter()
+        .filter_map(|(name, value)| {
+            let value = value.to_str().ok()?;
+            Some((name.as_str().to_string(), Value::from(value)))
+        })
+        .collect();
+    let header_values = Value::Object(header_values);
+    let header = |name: &str| header_values.get(name).and_then(Value::as_str);
+
+    // GitHub 웹훅 본문은 UTF-8 JSON
+    let body = String::from_utf8_lossy(&body);
+    let inbound = InboundDelivery {
+        delivery_guid: header("x-github-delivery"),
+        event: header("x-github-event"),
+        headers: &header_values,
+        body: &body,
+        replay_of: None,
+    };
+
+    let receipt = server.processor.receive(&inbound).await;
+    match receipt.outcome {
+        DeliveryOutcome::Handled(outcome) => (StatusCode::OK, outcome.as_str().to_string()),
+        DeliveryOutcome::Rejected(rejection) => {
+            (rejection_status(rejection), rejection.message().to_string())
+        }
+        DeliveryOutcome::Failed(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Failed to process webhook".to_string(),
+        ),
+    }
+}
+
+fn rejection_status(rejection: WebhookRejection) -> StatusCode {
+    match rejection {
+        WebhookRejection::MissingEvent | WebhookRejection::MissingRepository => {
+            StatusCode::BAD_REQUEST
+        }
+        WebhookRejection::UnknownRepository => StatusCode::NOT_FOUND,
+        WebhookRejection::WebhookDisabled => StatusCode::FORBIDDEN,
+        WebhookRejection::InvalidSignature => StatusCode::UNAUTHORIZED,
+    }
+}
@@ -0,0 +1,69 @@
+//! GitHub 웹훅 서명 검증
+//!
+//! `X-Hub-Signature-256` 헤더는 `sha256=` 접두사와 요청 본문의 HMAC-SHA256 hex 값
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// 서명 헤더 접두사
+const SIGNATURE_PREFIX: &str = "sha256=";
+
+/// 요청 본문 서명 검증 (상수 시간 비교)
+///
+/// # Arguments
+///
+/// * `secret` - 저장소 웹훅 비밀값
+/// * `signature` - `X-Hub-Signature-256` 헤더 값
+/// * `body` - 수신한 요청 본문 원문
+pub fn verify_signature(secret: &str, signature: &str, body: &[u8]) -> bool {
+    let Some(expected) = signature
+        .strip_prefix(SIGNATURE_PREFIX)
+        .and_then(|hex_value| hex::decode(hex_value).ok())
+    else {
+        return false;
+    };
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key");
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // GitHub 문서의 예시 값
+    const SECRET: &str = "It's a Secret to Everybody";
+    const BODY: &[u8] = b"Hello, World!";
+    const SIGNATURE: &str =
+        "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+
+    #[test]
+    fn accepts_valid_signature() {
+        assert!(verify_signature(SECRET, SIGNATURE, BODY));
+    }
+
+    #[test]
+    fn rejects_wrong_secret_or_body() {
+        assert!(!verify_signature("other secret", SIGNATURE, BODY));
+        assert!(!verify_signature(SECRET, SIGNATURE, b"Hello, World?"));
+    }
+
+    #[test]
+    fn rejects_malformed_header() {
+        let hex_value = SIGNATURE.strip_prefix(SIGNATURE_PREFIX).unwrap();
+        assert!(!verify_signature(SECRET, hex_value, BODY));
+        assert!(!verify_signature(
+            SECRET,
+            &format!("sha1={}", hex_value),
+            BODY
+        ));
+        assert!(!verify_signature(SECRET, "sha256=not-hex", BODY));
+        assert!(!verify_signature(SECRET, "sha256=", BODY));
+        assert!(!verify_signature(
+            SECRET,
+            &SIGNATURE[..SIGNATURE.len() - 2],
+            BODY
+        ));
+    }
+}
@@ -0,0 +1,251 @@
+//! 설정 변경 감사 로그
+//!
+//! 설정을 변경하는 명령은 `WsServer`가 핸들러 호출 전후로 대상 행을 스냅샷하여
+//! 실제로 변경된 경우에만 `audit_log`에 기록 (실패한 요청은 기록하지 않음)
+
+use crate::{
+    db::{self, AuditLogEntry},
+    ws::{ws_message::ClientMessageType, ws_server::HandlerContext},
+};
+use anyhow::Result;
+use serde_json::Value;
+use sqlx::PgPool;
+use tracing::error;
+use uuid::Uuid;
+
+/// 감사 로그에 기록하는 비밀값 대체 문자열
+const REDACTED: &str = "***";
+
+/// 감사 대상 행
+enum AuditTarget {
+    Repository(i32),
+    /// 등록 전이라 ID가 없는 저장소
+    RepositoryByName {
+        owner: String,
+        name: String,
+    },
+    /// 저장소의 Jenkins 매핑 목록 (새 매핑 추가)
+    JenkinsMappings(i32),
+    JenkinsMapping(i32),
+    /// 저장소의 브랜치 감시 규칙 목록
+    BranchWatchRules(i32),
+    BranchWatchRule(i32),
+    /// 저장소의 태그 규칙 목록
+    TagRules(i32),
+    TagRule(i32),
+    SystemSetting(String),
+    /// API 토큰 목록 (새 토큰 발급, 토큰 해시는 스냅샷에 포함되지 않음)
+    ApiTokens,
+    ApiToken(i32),
+}
+
+impl AuditTarget {
+    /// 명령이 변경하는 행 (감사 대상이 아니면 None)
+    fn from_message(message: &ClientMessageType) -> Option<Self> {
+        match message {
+            ClientMessageType::AddRepository { owner, name, .. } => {
+                Some(AuditTarget::RepositoryByName {
+                    owner: owner.clone(),
+                    name: name.clone(),
+                })
+            }
+            ClientMessageType::UpdateRepository { repo_id, .. }
+            | ClientMessageType::DeleteRepository { repo_id } => {
+                Some(AuditTarget::Repository(*repo_id))
+            }
+            ClientMessageType::SetJenkinsMapping {
+                repo_id,
+                mapping_id,
+                ..
+            } => Some(match mapping_id {
+                Some(mapping_id) => AuditTarget::JenkinsMapping(*mapping_id),
+                None => AuditTarget::JenkinsMappings(*repo_id),
+            }),
+            ClientMessageType::DeleteJenkinsMapping { mapping_id } => {
+                Some(AuditTarget::JenkinsMapping(*mapping_id))
+            }
+            ClientMessageType::AddBranchWatchRule { repo_id, .. } => {
+                Some(AuditTarget::BranchWatchRules(*repo_id))
+            }
+            ClientMessageType::DeleteBranchWatchRule { rule_id } => {
+                Some(AuditTarget::BranchWatchRule(*rule_id))
+            }
+            ClientMessageType::AddTagRule { repo_id, .. } => Some(AuditTarget::TagRules(*repo_id)),
+            ClientMessageType::DeleteTagRule { rule_id } => Some(AuditTarget::TagRule(*rule_id)),
+            ClientMessageType::UpdateSystemSetting { key, .. } => {
+                Some(AuditTarget::SystemSetting(key.clone()))
+            }
+            ClientMessageType::CreateApiToken { .. } => Some(AuditTarget::ApiTokens),
+            ClientMessageType::RevokeApiToken { token_id } => {
+                Some(AuditTarget::ApiToken(*token_id))
+            }
+            ClientMessageType::Authenticate { .. }
+            | ClientMessageType::TriggerBuild { .. }
+            | ClientMessageType::ReplayWebhookDelivery { .. }
+            | ClientMessageType::Subscribe { .. }
+            | ClientMessageType::Unsubscribe { .. }
+            | ClientMessageType::GetRepositories
+            | ClientMessageType::GetRepository { .. }
+            | ClientMessageType::GetPullRequests { .. }
+            | ClientMessageType::GetPullRequestHistory { .. }
+            | ClientMessageType::GetBuildHistory { .. }
+            | ClientMessageType::GetJenkinsMappings { .. }
+            | ClientMessageType::GetBranches { .. }
+            | ClientMessageType::GetBranchBuildHistory { .. }
+            | ClientMessageType::GetBranchWatchRules { .. }
+            | ClientMessageType::GetTags { .. }
+            | ClientMessageType::GetTagBuildHistory { .. }
+            | ClientMessageType::GetTagRules { .. }
+            | ClientMessageType::GetSystemSettings
+            | ClientMessageType::GetPollingHistory { .. }
+            | ClientMessageType::GetSubscriptions
+            | ClientMessageType::GetApiTokens
+            | ClientMessageType::GetAuditLog { .. }
+            | ClientMessageType::GetWebhookDeliveries { .. } => None,
+        }
+    }
+
+    /// 현재 행 스냅샷 (행이 없으면 None)
+    async fn snapshot(&self, pool: &PgPool) -> Result<Option<Value>> {
+        let snapshot = match self {
+            // 없는 저장소는 에러로 반환됨
+            AuditTarget::Repository(repo_id) => db::Queries::get_repository(pool, *repo_id)
+                .await
+                .ok()
+                .map(|repo| serde_json::to_value(repo).unwrap()),
+            AuditTarget::RepositoryByName { owner, name } => {
+                db::Queries::get_repository_by_name(pool, owner, name)
+                    .await?
+                    .map(|repo| serde_json::to_value(repo).unwrap())
+            }
+            AuditTarget::JenkinsMappings(repo_id) => {
+                let mappings = db::Queries::get_jenkins_mappings(pool, *repo_id).await?;
+                Some(serde_json::to_value(mappings).unwrap())
+            }
+            AuditTarget::JenkinsMapping(mapping_id) => {
+                db::Queries::get_jenkins_mapping(pool, *mapping_id)
+                    .await?
+                    .map(|mapping| serde_json::to_value(mapping).unwrap())
+            }
+            AuditTarget::BranchWatchRules(repo_id) => {
+                let rules = db::Queries::get_branch_watch_rules(pool, *repo_id).await?;
+                Some(serde_json::to_value(rules).unwrap())
+            }
+            AuditTarget::BranchWatchRule(rule_id) => {
+                db::Queries::get_branch_watch_rule(pool, *rule_id)
+                    .await?
+                    .map(|rule| serde_json::to_value(rule).unwrap())
+            }
+            AuditTarget::TagRules(repo_id) => {
+                let rules = db::Queries::get_tag_rules(pool, *repo_id).await?;
+                Some(serde_json::to_value(rules).unwrap())
+            }
+            AuditTarget::TagRule(rule_id) => db::Queries::get_tag_rule(pool, *rule_id)
+                .await?
+                .map(|rule| serde_json::to_value(rule).unwrap()),
+            AuditTarget::SystemSetting(key) => db::Queries::get_system_setting(pool, key)
+                .await?
+                .map(|setting| serde_json::to_value(setting).unwrap()),
+            AuditTarget::ApiTokens => {
+                let tokens = db::Queries::get_api_tokens(pool).await?;
+                Some(serde_json::to_value(tokens).unwrap())
+            }
+            AuditTarget::ApiToken(token_id) => db::Queries::get_api_token(pool, *token_id)
+                .await?
+                .map(|token| serde_json::to_value(token).unwrap()),
+        };
+        Ok(snapshot)
+    }
+}
+
+/// 핸들러 실행 전 상태를 담은 감사 기록
+pub struct PendingAudit {
+    target: AuditTarget,
+    message_type: String,
+    payload: Value,
+    before: Option<Value>,
+}
+
+impl PendingAudit {
+    /// 변경 전 스냅샷 생성 (감사 대상이 아니면 None)
+    pub async fn begin(pool: &PgPool, message: &ClientMessageType) -> Option<Self> {
+        let target = AuditTarget::from_message(message)?;
+        let before = match target.snapshot(pool).await {
+            Ok(before) => before,
+            Err(e) => {
+                error!("Failed to take audit snapshot: {}", e);
+                return None;
+            }
+        };
+
+        // {"type": ..., "data": ...} 형식으로 직렬화됨
+        let mut message = serde_json::to_value(redact(message)).unwrap();
+        let message_type = message["type"].as_str().unwrap_or_default().to_string();
+        let payload = message
+            .get_mut("data")
+            .map(Value::take)
+            .unwrap_or(Value::Null);
+
+        Some(PendingAudit {
+            target,
+            message_type,
+            payload,
+            before,
+        })
+    }
+
+    /// 변경 후 스냅샷과 비교하여 변경된 경우 기록
+    pub async fn finish(self, ctx: &HandlerContext, id: Uuid) {
+        let after = match self.target.snapshot(&ctx.pool).await {
+            Ok(after) => after,
+            Err(e) => {
+                error!("Failed to take audit snapshot: {}", e);
+                return;
+            }
+        };
+        if after == self.before {
+            return;
+        }
+
+        let (actor, remote_addr) = match ctx.clients.lock().await.get(&id) {
+            Some(client) => (
+                client
+                    .identity
+                    .as_ref()
+                    .map(|identity| identity.name.clone()),
+                Some(client.addr.to_string()),
+            ),
+            None => (None, None),
+        };
+        let client_id = id.to_string();
+
+        let entry = AuditLogEntry {
+            actor: actor.as_deref(),
+            client_id: &client_id,
+            remote_addr: remote_addr.as_deref(),
+            message_type: &self.message_type,
+            payload: &self.payload,
+            before_snapshot: self.before.as_ref(),
+            after_snapshot: after.as_ref(),
+        };
+        if let Err(e) = db::Queries::create_audit_log(&ctx.pool, &entry).await {
+            error!("Failed to record audit log: {}", e);
+        }
+    }
+}
+
+/// 감사 로그 조회로 노출되지 않도록 비밀값을 가린 명령
+///
+/// 웹훅 비밀값은 설정/해제 여부만 남김 (빈 문자열은 해제)
+fn redact(message: &ClientMessageType) -> ClientMessageType {
+    let mut message = message.clone();
+    if let ClientMessageType::UpdateRepository {
+        webhook_secret: Some(secret),
+        ..
+    } = &mut message
+        && !secret.is_empty()
+    {
+        *secret = REDACTED.to_string();
+    }
+    message
+}
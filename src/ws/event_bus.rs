@@ -0,0 +1,37 @@
+//! 실시간 이벤트 버스
+//!
+//! 폴러, 빌드 트리거, 빌드 추적 등 서버 내부 모듈이 이벤트를 발행하면
+//! `WsServer`가 구독하여 연결된 모든 클라이언트에 전송
+
+use crate::ws::ws_message::ServerMessageType;
+use tokio::sync::broadcast;
+
+/// 구독자가 처리하지 못한 이벤트를 보관하는 최대 개수
+const EVENT_BUS_CAPACITY: usize = 1024;
+
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    tx: broadcast::Sender<ServerMessageType>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        let (tx, _) = broadcast::channel(EVENT_BUS_CAPACITY);
+        EventBus { tx }
+    }
+
+    /// 이벤트 발행 (구독자가 없으면 버려짐)
+    pub fn publish(&self, event: ServerMessageType) {
+        let _ = self.tx.send(event);
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<ServerMessageType> {
+        self.tx.subscribe()
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{
+    db,
+    ws::{
+        ws_message::{
+            ClientMessage, ClientMessageType, ErrorCode, ServerMessage, ServerMessageType,
+        },
+        ws_server::{HandlerContext, WsServer},
+    },
+};
+use chrono::{DateTime, NaiveDateTime};
+use tracing::error;
+use uuid::Uuid;
+
+/// 감사 로그 기본 조회 개수
+const DEFAULT_AUDIT_LOG_LIMIT: i32 = 100;
+
+/// 감사 로그 최대 조회 개수
+const MAX_AUDIT_LOG_LIMIT: i32 = 1000;
+
+pub async fn audit_handler(ctx: HandlerContext, id: Uuid, msg: ClientMessage) {
+    match msg.payload {
+        ClientMessageType::GetAuditLog {
+            actor,
+            since,
+            until,
+            limit,
+        } => {
+            get_audit_log_handler(ctx, id, msg.id, actor, since, until, limit).await;
+        }
+        _ => {
+            error!("Invalid message: {:?}", msg);
+        }
+    }
+}
+
+pub async fn get_audit_log_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    actor: Option<String>,
+    since: Option<String>,
+    until: Option<String>,
+    limit: Option<i32>,
+) {
+    let limit = limit.unwrap_or(DEFAULT_AUDIT_LOG_LIMIT);
+    let validation = if (1..=MAX_AUDIT_LOG_LIMIT).contains(&limit) {
+        parse_time("since", since.as_deref())
+            .and_then(|since| Ok((since, parse_time("until", until.as_deref())?)))
+    } else {
+        Err(format!(
+            "limit must be between 1 and {}",
+            MAX_AUDIT_LOG_LIMIT
+        ))
+    };
+    let (since, until) = match validation {
+        Ok(range) => range,
+        Err(reason) => {
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::ValidationError,
+                &reason,
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let actor = actor
+        .as_deref()
+        .map(str::trim)
+        .filter(|actor| !actor.is_empty());
+    let entries =
+        match db::Queries::get_audit_log(&ctx.pool, actor, since, until, limit as i64).await {
+            Ok(entries) => entries,
+            Err(e) => {
+                error!("Failed to get audit log: {}", e);
+                WsServer::send_error_message(
+                    ctx.clients.clone(),
+                    id,
+                    msg_id,
+                    ErrorCode::DatabaseError,
+                    "Failed to get audit log",
+                    None,
+                )
+                .await;
+                return;
+            }
+        };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::AuditLog { entries },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+/// ISO 8601 시각 파싱 (예: "2025-01-01T09:00:00Z", "2025-01-01T18:00:00+09:00", 소수 초 허용)
+///
+/// 시간대가 있으면 UTC로 변환하고, 없으면 기록 시각과 같은 UTC 시각으로 간주
+fn parse_time(field: &str, value: Option<&str>) -> Result<Option<NaiveDateTime>, String> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|time| time.naive_utc())
+                .or_else(|_| value.parse::<NaiveDateTime>())
+                .map_err(|_| format!("{} must be an ISO 8601 timestamp", field))
+        })
+        .transpose()
+}
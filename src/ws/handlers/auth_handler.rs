@@ -0,0 +1,268 @@
+use crate::{
+    auth,
+    db::{self, Role},
+    ws::{
+        ws_message::{
+            ClientMessage, ClientMessageType, ErrorCode, ServerMessage, ServerMessageType,
+        },
+        ws_server::{HandlerContext, WsServer},
+    },
+};
+use serde_json::json;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+pub async fn auth_handler(ctx: HandlerContext, id: Uuid, msg: ClientMessage) {
+    match msg.payload {
+        ClientMessageType::Authenticate { token } => {
+            authenticate_handler(ctx, id, msg.id, token).await;
+        }
+        ClientMessageType::CreateApiToken { name, role } => {
+            create_api_token_handler(ctx, id, msg.id, name, role).await;
+        }
+        ClientMessageType::RevokeApiToken { token_id } => {
+            revoke_api_token_handler(ctx, id, msg.id, token_id).await;
+        }
+        ClientMessageType::GetApiTokens => {
+            get_api_tokens_handler(ctx, id, msg.id).await;
+        }
+        _ => {
+            error!("Invalid message: {:?}", msg);
+        }
+    }
+}
+
+/// 연결 인증
+///
+/// 업그레이드 요청에 Bearer 헤더를 보내지 못하는 클라이언트(브라우저 등)용
+pub async fn authenticate_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    token: String,
+) {
+    let identity = match auth::authenticate(&ctx.pool, token.trim()).await {
+        Ok(Some(identity)) => identity,
+        Ok(None) => {
+            warn!("Authentication failed: {}", id);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::Unauthorized,
+                "Invalid API token",
+                None,
+            )
+            .await;
+            return;
+        }
+        Err(e) => {
+            error!("Failed to authenticate: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to authenticate",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    info!(
+        "Client {} authenticated: {} ({})",
+        id,
+        identity.name,
+        identity.role.as_str()
+    );
+    let data = serde_json::to_value(&identity).unwrap();
+    if let Some(client) = ctx.clients.lock().await.get_mut(&id) {
+        client.identity = Some(identity);
+    }
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::Success {
+            message: "Authenticated successfully".to_string(),
+            data: Some(data),
+        },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+/// API 토큰 발급
+///
+/// 토큰 원문은 저장하지 않으므로 응답의 `token` 값을 다시 조회할 수 없음
+pub async fn create_api_token_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    name: String,
+    role: Option<String>,
+) {
+    let name = name.trim();
+    if name.is_empty() || name.len() > 255 {
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            msg_id,
+            ErrorCode::ValidationError,
+            "name must be between 1 and 255 characters",
+            None,
+        )
+        .await;
+        return;
+    }
+
+    let role = match role.as_deref().map(Role::parse) {
+        None => Role::Viewer,
+        Some(Some(role)) => role,
+        Some(None) => {
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::ValidationError,
+                "role must be one of viewer, operator, admin",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let token = auth::generate_token();
+    let token_hash = auth::hash_token(&token);
+    let api_token = match db::Queries::create_api_token(&ctx.pool, name, &token_hash, role).await {
+        Ok(api_token) => api_token,
+        Err(e) => {
+            error!("Failed to create api token: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to create api token",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let mut data = serde_json::to_value(&api_token).unwrap();
+    data["token"] = json!(token);
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::Success {
+            message: "API token created successfully".to_string(),
+            data: Some(data),
+        },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+/// API 토큰 폐기
+///
+/// 이미 이 토큰으로 인증된 연결도 인증을 해제하여 이후 권한이 필요한 명령은 거부
+pub async fn revoke_api_token_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    token_id: i32,
+) {
+    let api_token = match db::Queries::revoke_api_token(&ctx.pool, token_id).await {
+        Ok(api_token) => api_token,
+        Err(e) => {
+            error!("Failed to revoke api token: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::NotFound,
+                "API token not found",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    // 폐기한 토큰으로 인증된 연결은 즉시 미인증 상태로 전환
+    let mut deauthenticated = 0;
+    for client in ctx.clients.lock().await.values_mut() {
+        if client
+            .identity
+            .as_ref()
+            .is_some_and(|identity| identity.token_id == token_id)
+        {
+            client.identity = None;
+            deauthenticated += 1;
+        }
+    }
+    info!(
+        "API token {} revoked: {} connection(s) deauthenticated",
+        token_id, deauthenticated
+    );
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::Success {
+            message: "API token revoked successfully".to_string(),
+            data: Some(serde_json::to_value(&api_token).unwrap()),
+        },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+pub async fn get_api_tokens_handler(ctx: HandlerContext, id: Uuid, msg_id: Option<String>) {
+    let tokens = match db::Queries::get_api_tokens(&ctx.pool).await {
+        Ok(tokens) => tokens,
+        Err(e) => {
+            error!("Failed to get api tokens: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to get api tokens",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::ApiTokens { tokens },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
@@ -0,0 +1,279 @@
+use crate::{
+    db,
+    rules::Pattern,
+    ws::{
+        ws_message::{
+            ClientMessage, ClientMessageType, ErrorCode, ServerMessage, ServerMessageType,
+        },
+        ws_server::{HandlerContext, WsServer},
+    },
+};
+use tracing::error;
+use uuid::Uuid;
+
+pub async fn branch_handler(ctx: HandlerContext, id: Uuid, msg: ClientMessage) {
+    match msg.payload {
+        ClientMessageType::AddBranchWatchRule { repo_id, pattern } => {
+            add_branch_watch_rule_handler(ctx, id, msg.id, repo_id, pattern).await;
+        }
+        ClientMessageType::DeleteBranchWatchRule { rule_id } => {
+            delete_branch_watch_rule_handler(ctx, id, msg.id, rule_id).await;
+        }
+        ClientMessageType::GetBranches { repo_id } => {
+            get_branches_handler(ctx, id, msg.id, repo_id).await;
+        }
+        ClientMessageType::GetBranchBuildHistory { branch_id } => {
+            get_branch_build_history_handler(ctx, id, msg.id, branch_id).await;
+        }
+        ClientMessageType::GetBranchWatchRules { repo_id } => {
+            get_branch_watch_rules_handler(ctx, id, msg.id, repo_id).await;
+        }
+        _ => {
+            error!("Invalid message: {:?}", msg);
+        }
+    }
+}
+
+/// 브랜치 감시 규칙 추가
+///
+/// 이후 패턴에 맞는 브랜치의 head가 바뀌면 Jenkins 매핑으로 빌드 트리거
+pub async fn add_branch_watch_rule_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    repo_id: i32,
+    pattern: String,
+) {
+    let pattern = pattern.trim();
+    if let Err(e) = Pattern::new(pattern) {
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            msg_id,
+            ErrorCode::ValidationError,
+            &e.to_string(),
+            None,
+        )
+        .await;
+        return;
+    }
+
+    if db::Queries::get_repository(&ctx.pool, repo_id)
+        .await
+        .is_err()
+    {
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            msg_id,
+            ErrorCode::NotFound,
+            "Repository not found",
+            None,
+        )
+        .await;
+        return;
+    }
+
+    let rule = match db::Queries::create_branch_watch_rule(&ctx.pool, repo_id, pattern).await {
+        Ok(Some(rule)) => rule,
+        Ok(None) => {
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::AlreadyExists,
+                "Branch watch rule already exists",
+                None,
+            )
+            .await;
+            return;
+        }
+        Err(e) => {
+            error!("Failed to create branch watch rule: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to create branch watch rule",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::Success {
+            message: "Branch watch rule added successfully".to_string(),
+            data: Some(serde_json::to_value(&rule).unwrap()),
+        },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+pub async fn delete_branch_watch_rule_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    rule_id: i32,
+) {
+    let rule = match db::Queries::delete_branch_watch_rule(&ctx.pool, rule_id).await {
+        Ok(Some(rule)) => rule,
+        Ok(None) => {
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::NotFound,
+                "Branch watch rule not found",
+                None,
+            )
+            .await;
+            return;
+        }
+        Err(e) => {
+            error!("Failed to delete branch watch rule: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to delete branch watch rule",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::Success {
+            message: "Branch watch rule deleted successfully".to_string(),
+            data: Some(serde_json::to_value(&rule).unwrap()),
+        },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+pub async fn get_branches_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    repo_id: i32,
+) {
+    let branches = match db::Queries::get_branches(&ctx.pool, repo_id).await {
+        Ok(branches) => branches,
+        Err(e) => {
+            error!("Failed to get branches: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to get branches",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::Branches { branches },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+pub async fn get_branch_build_history_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    branch_id: i32,
+) {
+    let builds = match db::Queries::get_branch_build_triggers(&ctx.pool, branch_id).await {
+        Ok(builds) => builds,
+        Err(e) => {
+            error!("Failed to get branch build history: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to get branch build history",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::BuildHistory { builds },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+pub async fn get_branch_watch_rules_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    repo_id: i32,
+) {
+    let rules = match db::Queries::get_branch_watch_rules(&ctx.pool, repo_id).await {
+        Ok(rules) => rules,
+        Err(e) => {
+            error!("Failed to get branch watch rules: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to get branch watch rules",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::BranchWatchRules { rules },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
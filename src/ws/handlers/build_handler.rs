@@ -0,0 +1,217 @@
+use crate::{
+    db::{self, BuildTrigger, PullRequest},
+    jenkins::{BuildTarget, MatchedMapping},
+    ws::{
+        ws_message::{
+            ClientMessage, ClientMessageType, ErrorCode, ServerMessage, ServerMessageType,
+        },
+        ws_server::{HandlerContext, WsServer},
+    },
+};
+use anyhow::Result;
+use tracing::error;
+use uuid::Uuid;
+
+pub async fn build_handler(ctx: HandlerContext, id: Uuid, msg: ClientMessage) {
+    match msg.payload {
+        ClientMessageType::TriggerBuild {
+            pr_id,
+            mapping_id,
+            force,
+        } => {
+            trigger_build_handler(ctx, id, msg.id, pr_id, mapping_id, force).await;
+        }
+        ClientMessageType::GetBuildHistory { pr_id } => {
+            get_build_history_handler(ctx, id, msg.id, pr_id).await;
+        }
+        _ => {
+            error!("Invalid message: {:?}", msg);
+        }
+    }
+}
+
+/// 수동 빌드 트리거
+///
+/// `mapping_id`가 없으면 트리거 조건에 맞는 모든 매핑으로 빌드하며 (자동 트리거 설정 무관),
+/// 트리거 이력을 생성한 뒤 바로 응답하고 Jenkins 요청은 백그라운드에서 진행
+pub async fn trigger_build_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    pr_id: i32,
+    mapping_id: Option<i32>,
+    force: bool,
+) {
+    let pr = match db::Queries::get_pull_request(&ctx.pool, pr_id).await {
+        Ok(pr) => pr,
+        Err(e) => {
+            error!("Failed to get pull request: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::NotFound,
+                "Pull request not found",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let mappings = match build_mappings(&ctx, &pr, mapping_id).await {
+        Ok(mappings) if !mappings.is_empty() => mappings,
+        Ok(_) => {
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::NotFound,
+                "Jenkins mapping not found",
+                None,
+            )
+            .await;
+            return;
+        }
+        Err(e) => {
+            error!("Failed to get jenkins mappings: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to get jenkins mappings",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let mut builds = Vec::new();
+    let mut failed = false;
+    for MatchedMapping {
+        mapping,
+        matched_paths,
+    } in mappings
+    {
+        match ctx
+            .triggers
+            .create_manual_trigger(&mapping, &pr, force, matched_paths.as_deref())
+            .await
+        {
+            Ok(Some(trigger)) => builds.push((mapping, trigger)),
+            Ok(None) => {}
+            Err(e) => {
+                error!(
+                    "Failed to create build trigger: {}: {}",
+                    mapping.jenkins_job_name, e
+                );
+                failed = true;
+            }
+        }
+    }
+    if builds.is_empty() {
+        let (code, message) = if failed {
+            (ErrorCode::DatabaseError, "Failed to create build trigger")
+        } else {
+            (
+                ErrorCode::AlreadyExists,
+                "Build already triggered for this commit (use force to rebuild)",
+            )
+        };
+        WsServer::send_error_message(ctx.clients.clone(), id, msg_id, code, message, None).await;
+        return;
+    }
+
+    let triggers: Vec<&BuildTrigger> = builds.iter().map(|(_, trigger)| trigger).collect();
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::Success {
+            message: "Build trigger requested".to_string(),
+            data: Some(serde_json::to_value(&triggers).unwrap()),
+        },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+
+    for (mapping, trigger) in builds {
+        let triggers = ctx.triggers.clone();
+        let pr = pr.clone();
+        tokio::spawn(async move {
+            if let Err(e) = triggers
+                .execute(&mapping, BuildTarget::PullRequest(&pr), trigger)
+                .await
+            {
+                error!(
+                    "Failed to record build trigger: PR #{}: {}",
+                    pr.pr_number, e
+                );
+            }
+        });
+    }
+}
+
+/// 수동 빌드에 사용할 매핑 (지정한 매핑 또는 트리거 조건에 맞는 매핑 전체)
+async fn build_mappings(
+    ctx: &HandlerContext,
+    pr: &PullRequest,
+    mapping_id: Option<i32>,
+) -> Result<Vec<MatchedMapping>> {
+    if let Some(mapping_id) = mapping_id {
+        let mapping = db::Queries::get_jenkins_mapping(&ctx.pool, mapping_id).await?;
+        return Ok(mapping
+            .filter(|mapping| mapping.repository_id == pr.repository_id)
+            .map(|mapping| MatchedMapping {
+                mapping,
+                matched_paths: None,
+            })
+            .into_iter()
+            .collect());
+    }
+
+    let repo = db::Queries::get_repository(&ctx.pool, pr.repository_id).await?;
+    let mappings = db::Queries::get_jenkins_mappings(&ctx.pool, pr.repository_id).await?;
+    ctx.triggers.matching_mappings(&repo, &mappings, pr).await
+}
+
+pub async fn get_build_history_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    pr_id: i32,
+) {
+    let builds = match db::Queries::get_build_triggers(&ctx.pool, pr_id).await {
+        Ok(builds) => builds,
+        Err(e) => {
+            error!("Failed to get build history: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to get build history",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::BuildHistory { builds },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
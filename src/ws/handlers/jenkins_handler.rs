@@ -0,0 +1,497 @@
+use crate::{
+    db::{self, JenkinsMappingInput, Role},
+    jenkins::validate_template,
+    rules::{self, MAX_LABEL_LEN},
+    ws::{
+        handlers::tag_handler::validate_parameters,
+        ws_message::{
+            ClientMessage, ClientMessageType, ErrorCode, ServerMessage, ServerMessageType,
+        },
+        ws_server::{HandlerContext, WsServer},
+    },
+};
+use reqwest::Url;
+use serde_json::Value;
+use tracing::error;
+use uuid::Uuid;
+
+/// 디바운스 최대 시간 (초)
+const MAX_DEBOUNCE_SECONDS: i32 = 3600;
+
+pub async fn jenkins_handler(ctx: HandlerContext, id: Uuid, msg: ClientMessage) {
+    match msg.payload {
+        ClientMessageType::SetJenkinsMapping {
+            repo_id,
+            mapping_id,
+            jenkins_job_name,
+            jenkins_url,
+            auto_trigger,
+            status_context,
+            target_branch_patterns,
+            source_branch_patterns,
+            path_patterns,
+            excluded_path_patterns,
+            required_labels,
+            excluded_labels,
+            parameters,
+            debounce_seconds,
+            skip_draft,
+            skip_ci_commits,
+            cancel_previous,
+        } => {
+            let filters = MappingFilters {
+                target_branch_patterns,
+                source_branch_patterns,
+                path_patterns,
+                excluded_path_patterns,
+                required_labels,
+                excluded_labels,
+            };
+            let rules = AutoBuildRules {
+                debounce_seconds,
+                skip_draft,
+                skip_ci_commits,
+                cancel_previous,
+            };
+            set_jenkins_mapping_handler(
+                ctx,
+                id,
+                msg.id,
+                repo_id,
+                mapping_id,
+                jenkins_job_name,
+                jenkins_url,
+                auto_trigger,
+                status_context,
+                filters,
+                parameters,
+                rules,
+            )
+            .await;
+        }
+        ClientMessageType::DeleteJenkinsMapping { mapping_id } => {
+            delete_jenkins_mapping_handler(ctx, id, msg.id, mapping_id).await;
+        }
+        ClientMessageType::GetJenkinsMappings { repo_id } => {
+            get_jenkins_mappings_handler(ctx, id, msg.id, repo_id).await;
+        }
+        _ => {
+            error!("Invalid message: {:?}", msg);
+        }
+    }
+}
+
+/// 매핑 트리거 조건 (None이면 신규 매핑은 조건 없음, 기존 매핑은 현재 값 유지)
+pub struct MappingFilters {
+    pub target_branch_patterns: Option<Vec<String>>,
+    pub source_branch_patterns: Option<Vec<String>>,
+    pub path_patterns: Option<Vec<String>>,
+    pub excluded_path_patterns: Option<Vec<String>>,
+    pub required_labels: Option<Vec<String>>,
+    pub excluded_labels: Option<Vec<String>>,
+}
+
+impl MappingFilters {
+    /// 앞뒤 공백과 빈 항목 제거
+    fn normalize(self) -> Self {
+        let normalize = |values: Option<Vec<String>>| {
+            values.map(|values| {
+                values
+                    .iter()
+                    .map(|value| value.trim())
+                    .filter(|value| !value.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+        };
+        MappingFilters {
+            target_branch_patterns: normalize(self.target_branch_patterns),
+            source_branch_patterns: normalize(self.source_branch_patterns),
+            path_patterns: normalize(self.path_patterns),
+            excluded_path_patterns: normalize(self.excluded_path_patterns),
+            required_labels: normalize(self.required_labels),
+            excluded_labels: normalize(self.excluded_labels),
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        for patterns in [
+            &self.target_branch_patterns,
+            &self.source_branch_patterns,
+            &self.path_patterns,
+            &self.excluded_path_patterns,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            rules::compile(patterns).map_err(|e| e.to_string())?;
+        }
+        for (field, labels) in [
+            ("required_labels", &self.required_labels),
+            ("excluded_labels", &self.excluded_labels),
+        ] {
+            if let Some(labels) = labels
+                && labels.iter().any(|label| label.len() > MAX_LABEL_LEN)
+            {
+                return Err(format!(
+                    "{} must be at most {} characters",
+                    field, MAX_LABEL_LEN
+                ));
+            }
+        }
+        if let (Some(required), Some(excluded)) = (&self.required_labels, &self.excluded_labels)
+            && let Some(label) = required
+                .iter()
+                .find(|label| excluded.iter().any(|e| e.eq_ignore_ascii_case(label)))
+        {
+            return Err(format!(
+                "Label {} cannot be both required and excluded",
+                label
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 자동 PR 빌드 설정 (None이면 신규 매핑은 꺼짐, 기존 매핑은 현재 값 유지)
+pub struct AutoBuildRules {
+    pub debounce_seconds: Option<i32>,
+    pub skip_draft: Option<bool>,
+    pub skip_ci_commits: Option<bool>,
+    pub cancel_previous: Option<bool>,
+}
+
+impl AutoBuildRules {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(seconds) = self.debounce_seconds
+            && !(0..=MAX_DEBOUNCE_SECONDS).contains(&seconds)
+        {
+            return Err(format!(
+                "debounce_seconds must be between 0 and {}",
+                MAX_DEBOUNCE_SECONDS
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Jenkins 매핑 추가 (`mapping_id`가 없을 때) 또는 수정
+#[allow(clippy::too_many_arguments)]
+pub async fn set_jenkins_mapping_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    repo_id: i32,
+    mapping_id: Option<i32>,
+    jenkins_job_name: String,
+    jenkins_url: String,
+    auto_trigger: bool,
+    status_context: Option<String>,
+    filters: MappingFilters,
+    parameters: Option<Value>,
+    rules: AutoBuildRules,
+) {
+    let jenkins_job_name = jenkins_job_name.trim().trim_matches('/').to_string();
+    let jenkins_url = jenkins_url.trim();
+    let jenkins_url = (!jenkins_url.is_empty()).then_some(jenkins_url);
+    let status_context = status_context
+        .as_deref()
+        .map(str::trim)
+        .filter(|context| !context.is_empty());
+    let filters = filters.normalize();
+
+    let validation = validate_job_name(&jenkins_job_name)
+        .and_then(|_| jenkins_url.map_or(Ok(()), validate_jenkins_url))
+        .and_then(|_| status_context.map_or(Ok(()), validate_status_context))
+        .and_then(|_| filters.validate())
+        .and_then(|_| rules.validate())
+        .and_then(|_| {
+            parameters
+                .as_ref()
+                .map_or(Ok(()), validate_parameter_templates)
+        });
+    if let Err(reason) = validation {
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            msg_id,
+            ErrorCode::ValidationError,
+            &reason,
+            None,
+        )
+        .await;
+        return;
+    }
+
+    if db::Queries::get_repository(&ctx.pool, repo_id)
+        .await
+        .is_err()
+    {
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            msg_id,
+            ErrorCode::NotFound,
+            "Repository not found",
+            None,
+        )
+        .await;
+        return;
+    }
+
+    let current_url = match mapping_id {
+        Some(mapping_id) => match db::Queries::get_jenkins_mapping(&ctx.pool, mapping_id).await {
+            Ok(Some(mapping)) if mapping.repository_id == repo_id => mapping.jenkins_url,
+            Ok(_) => {
+                WsServer::send_error_message(
+                    ctx.clients.clone(),
+                    id,
+                    msg_id,
+                    ErrorCode::NotFound,
+                    "Jenkins mapping not found",
+                    None,
+                )
+                .await;
+                return;
+            }
+            Err(e) => {
+                error!("Failed to get jenkins mapping: {}", e);
+                WsServer::send_error_message(
+                    ctx.clients.clone(),
+                    id,
+                    msg_id,
+                    ErrorCode::DatabaseError,
+                    "Failed to get jenkins mapping",
+                    None,
+                )
+                .await;
+                return;
+            }
+        },
+        None => None,
+    };
+
+    // 전역 Jenkins 인증 정보가 지정한 주소로 전송되므로 다른 서버 지정은 Admin만 가능
+    // (전역 설정으로 되돌리는 것은 허용)
+    let url_changed = jenkins_url
+        .is_some_and(|url| current_url.as_deref().filter(|url| !url.is_empty()) != Some(url));
+    if url_changed && let Err((code, _)) = WsServer::authorize(&ctx.clients, id, Role::Admin).await
+    {
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            msg_id,
+            code,
+            "admin role required to change jenkins_url",
+            None,
+        )
+        .await;
+        return;
+    }
+
+    let input = JenkinsMappingInput {
+        jenkins_job_name: &jenkins_job_name,
+        jenkins_url,
+        auto_trigger,
+        status_context,
+        target_branch_patterns: filters.target_branch_patterns.as_deref(),
+        source_branch_patterns: filters.source_branch_patterns.as_deref(),
+        path_patterns: filters.path_patterns.as_deref(),
+        excluded_path_patterns: filters.excluded_path_patterns.as_deref(),
+        required_labels: filters.required_labels.as_deref(),
+        excluded_labels: filters.excluded_labels.as_deref(),
+        parameters: parameters.as_ref(),
+        debounce_seconds: rules.debounce_seconds,
+        skip_draft: rules.skip_draft,
+        skip_ci_commits: rules.skip_ci_commits,
+        cancel_previous: rules.cancel_previous,
+    };
+    let result = match mapping_id {
+        Some(mapping_id) => {
+            db::Queries::update_jenkins_mapping(&ctx.pool, mapping_id, &input).await
+        }
+        None => db::Queries::create_jenkins_mapping(&ctx.pool, repo_id, &input).await,
+    };
+    let mapping = match result {
+        Ok(Some(mapping)) => mapping,
+        Ok(None) => {
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::AlreadyExists,
+                "status_context is already used by another Jenkins mapping",
+                None,
+            )
+            .await;
+            return;
+        }
+        Err(e) => {
+            error!("Failed to set jenkins mapping: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to set jenkins mapping",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::Success {
+            message: "Jenkins mapping saved successfully".to_string(),
+            data: Some(serde_json::to_value(&mapping).unwrap()),
+        },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+pub async fn delete_jenkins_mapping_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    mapping_id: i32,
+) {
+    let mapping = match db::Queries::delete_jenkins_mapping(&ctx.pool, mapping_id).await {
+        Ok(Some(mapping)) => mapping,
+        Ok(None) => {
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::NotFound,
+                "Jenkins mapping not found",
+                None,
+            )
+            .await;
+            return;
+        }
+        Err(e) => {
+            error!("Failed to delete jenkins mapping: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to delete jenkins mapping",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::Success {
+            message: "Jenkins mapping deleted successfully".to_string(),
+            data: Some(serde_json::to_value(&mapping).unwrap()),
+        },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+pub async fn get_jenkins_mappings_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    repo_id: i32,
+) {
+    let mappings = match db::Queries::get_jenkins_mappings(&ctx.pool, repo_id).await {
+        Ok(mappings) => mappings,
+        Err(e) => {
+            error!("Failed to get jenkins mappings: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to get jenkins mappings",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::JenkinsMappings { mappings },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+/// 빌드 파라미터 템플릿 검증 (알 수 없는 변수가 있으면 에러)
+fn validate_parameter_templates(parameters: &Value) -> Result<(), String> {
+    validate_parameters(parameters)?;
+    for (name, value) in parameters.as_object().into_iter().flatten() {
+        let template = value.as_str().unwrap_or_default();
+        validate_template(template).map_err(|e| format!("parameter {:?}: {}", name, e))?;
+    }
+    Ok(())
+}
+
+/// Job 이름 검증 (폴더는 `folder/job` 형식)
+pub(crate) fn validate_job_name(job_name: &str) -> Result<(), String> {
+    if job_name.is_empty() {
+        return Err("jenkins_job_name is required".to_string());
+    }
+    if job_name.len() > 255 {
+        return Err("jenkins_job_name must be at most 255 characters".to_string());
+    }
+    if job_name.split('/').any(|part| part.trim().is_empty()) {
+        return Err("jenkins_job_name has an empty folder segment".to_string());
+    }
+    if job_name
+        .chars()
+        .any(|c| c.is_control() || matches!(c, '?' | '#' | '%' | '\\'))
+    {
+        return Err("jenkins_job_name contains invalid characters".to_string());
+    }
+    Ok(())
+}
+
+/// Jenkins 주소 검증 (http/https 절대 주소)
+fn validate_jenkins_url(jenkins_url: &str) -> Result<(), String> {
+    if jenkins_url.len() > 512 {
+        return Err("jenkins_url must be at most 512 characters".to_string());
+    }
+    let url = Url::parse(jenkins_url).map_err(|e| format!("Invalid jenkins_url: {}", e))?;
+    if !matches!(url.scheme(), "http" | "https") || url.host_str().is_none() {
+        return Err("jenkins_url must be an http(s) URL".to_string());
+    }
+    if url.query().is_some() || url.fragment().is_some() {
+        return Err("jenkins_url must not contain a query or fragment".to_string());
+    }
+    Ok(())
+}
+
+fn validate_status_context(status_context: &str) -> Result<(), String> {
+    if status_context.len() > 255 {
+        return Err("status_context must be at most 255 characters".to_string());
+    }
+    Ok(())
+}
@@ -1 +1,11 @@
-pub mod repository_handler;
\ No newline at end of file
+pub mod audit_handler;
+pub mod auth_handler;
+pub mod branch_handler;
+pub mod build_handler;
+pub mod jenkins_handler;
+pub mod pull_request_handler;
+pub mod repository_handler;
+pub mod subscription_handler;
+pub mod system_setting_handler;
+pub mod tag_handler;
+pub mod webhook_handler;
@@ -0,0 +1,131 @@
+use crate::{
+    db::{self, PrStatus},
+    ws::{
+        ws_message::{
+            ClientMessage, ClientMessageType, ErrorCode, ServerMessage, ServerMessageType,
+        },
+        ws_server::{HandlerContext, WsServer},
+    },
+};
+use tracing::error;
+use uuid::Uuid;
+
+pub async fn pull_request_handler(ctx: HandlerContext, id: Uuid, msg: ClientMessage) {
+    match msg.payload {
+        ClientMessageType::GetPullRequests { repo_id, status } => {
+            get_pull_requests_handler(ctx, id, msg.id, repo_id, status).await;
+        }
+        ClientMessageType::GetPullRequestHistory { pr_id } => {
+            get_pull_request_history_handler(ctx, id, msg.id, pr_id).await;
+        }
+        _ => {
+            error!("Invalid message: {:?}", msg);
+        }
+    }
+}
+
+pub async fn get_pull_requests_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    repo_id: i32,
+    status: Option<String>,
+) {
+    if let Some(status) = status.as_deref()
+        && PrStatus::parse(status).is_none()
+    {
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            msg_id,
+            ErrorCode::ValidationError,
+            "status must be one of open, draft, closed, merged, reopened",
+            None,
+        )
+        .await;
+        return;
+    }
+
+    let pull_requests =
+        match db::Queries::get_pull_requests(&ctx.pool, repo_id, status.as_deref()).await {
+            Ok(pull_requests) => pull_requests,
+            Err(e) => {
+                error!("Failed to get pull request list: {}", e);
+                WsServer::send_error_message(
+                    ctx.clients.clone(),
+                    id,
+                    msg_id,
+                    ErrorCode::DatabaseError,
+                    "Failed to get pull request list",
+                    None,
+                )
+                .await;
+                return;
+            }
+        };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::PullRequests { pull_requests },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+/// PR 상태 전이 이력 조회
+pub async fn get_pull_request_history_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    pr_id: i32,
+) {
+    if db::Queries::get_pull_request(&ctx.pool, pr_id)
+        .await
+        .is_err()
+    {
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            msg_id,
+            ErrorCode::NotFound,
+            "Pull request not found",
+            None,
+        )
+        .await;
+        return;
+    }
+
+    let transitions = match db::Queries::get_pull_request_transitions(&ctx.pool, pr_id).await {
+        Ok(transitions) => transitions,
+        Err(e) => {
+            error!("Failed to get pull request history: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to get pull request history",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::PullRequestHistory { transitions },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
@@ -1,66 +1,58 @@
-use std::{collections::HashMap, sync::Arc};
-
 use crate::{
     db,
     ws::{
         ws_message::{
             ClientMessage, ClientMessageType, ErrorCode, ServerMessage, ServerMessageType,
         },
-        ws_server::{Client, WsServer},
+        ws_server::{HandlerContext, WsServer},
     },
 };
-use sqlx::PgPool;
-use tokio::sync::Mutex;
 use tracing::error;
 use uuid::Uuid;
 
-pub async fn repository_handler(
-    clients: Arc<Mutex<HashMap<Uuid, Client>>>,
-    id: Uuid,
-    pool: PgPool,
-    msg: ClientMessage,
-) {
+/// 폴링 이력 조회 기본 개수
+const DEFAULT_POLLING_HISTORY_LIMIT: i32 = 50;
+
+/// 폴링 이력 조회 최대 개수
+const MAX_POLLING_HISTORY_LIMIT: i32 = 1000;
+
+pub async fn repository_handler(ctx: HandlerContext, id: Uuid, msg: ClientMessage) {
     match msg.payload {
         ClientMessageType::AddRepository {
             owner,
             name,
             poll_interval_seconds,
         } => {
-            add_repository_handler(
-                clients,
-                id,
-                pool,
-                msg.id,
-                owner,
-                name,
-                poll_interval_seconds,
-            )
-            .await;
+            add_repository_handler(ctx, id, msg.id, owner, name, poll_interval_seconds).await;
         }
         ClientMessageType::UpdateRepository {
             repo_id,
             is_active,
             poll_interval_seconds,
+            webhook_secret,
         } => {
             update_repository_handler(
-                clients,
+                ctx,
                 id,
-                pool,
                 msg.id,
                 repo_id,
                 is_active,
                 poll_interval_seconds,
+                webhook_secret,
             )
             .await;
         }
         ClientMessageType::DeleteRepository { repo_id } => {
-            delete_repository_handler(clients, id, pool, msg.id, repo_id).await;
+            delete_repository_handler(ctx, id, msg.id, repo_id).await;
         }
-        ClientMessageType::GetRepositories {} => {
-            get_repositories_handler(clients, id, pool, msg.id).await;
+        ClientMessageType::GetRepositories => {
+            get_repositories_handler(ctx, id, msg.id).await;
         }
         ClientMessageType::GetRepository { repo_id } => {
-            get_repository_handler(clients, id, pool, msg.id, repo_id).await;
+            get_repository_handler(ctx, id, msg.id, repo_id).await;
+        }
+        ClientMessageType::GetPollingHistory { repo_id, limit } => {
+            get_polling_history_handler(ctx, id, msg.id, repo_id, limit).await;
         }
         _ => {
             error!("Invalid message: {:?}", msg);
@@ -69,31 +61,32 @@ pub async fn repository_handler(
 }
 
 pub async fn add_repository_handler(
-    clients: Arc<Mutex<HashMap<Uuid, Client>>>,
+    ctx: HandlerContext,
     id: Uuid,
-    pool: PgPool,
     msg_id: Option<String>,
     owner: String,
     name: String,
     poll_interval_seconds: Option<i32>,
 ) {
-    let repo = match db::Queries::create_repository(&pool, owner, name, poll_interval_seconds).await
-    {
-        Ok(repo) => repo,
-        Err(e) => {
-            error!("Failed to add repository: {}", e);
-            WsServer::send_error_message(
-                clients.clone(),
-                id,
-                msg_id,
-                ErrorCode::DatabaseError,
-                "Failed to add repository",
-                None,
-            )
-            .await;
-            return;
-        }
-    };
+    let repo =
+        match db::Queries::create_repository(&ctx.pool, owner, name, poll_interval_seconds).await {
+            Ok(repo) => repo,
+            Err(e) => {
+                error!("Failed to add repository: {}", e);
+                WsServer::send_error_message(
+                    ctx.clients.clone(),
+                    id,
+                    msg_id,
+                    ErrorCode::DatabaseError,
+                    "Failed to add repository",
+                    None,
+                )
+                .await;
+                return;
+            }
+        };
+
+    ctx.scheduler.repository_changed(repo.id);
 
     let server_message = ServerMessage {
         id: msg_id,
@@ -104,25 +97,20 @@ pub async fn add_repository_handler(
     };
 
     WsServer::send_message(
-        clients.clone(),
+        ctx.clients.clone(),
         id,
         serde_json::to_string(&server_message).unwrap().as_str(),
     )
     .await;
 }
 
-pub async fn get_repositories_handler(
-    clients: Arc<Mutex<HashMap<Uuid, Client>>>,
-    id: Uuid,
-    pool: PgPool,
-    msg_id: Option<String>,
-) {
-    let repo_list = match db::Queries::get_repositories(&pool).await {
+pub async fn get_repositories_handler(ctx: HandlerContext, id: Uuid, msg_id: Option<String>) {
+    let repo_list = match db::Queries::get_repositories(&ctx.pool).await {
         Ok(repo_list) => repo_list,
         Err(e) => {
             error!("Failed to get repository list: {}", e);
             WsServer::send_error_message(
-                clients.clone(),
+                ctx.clients.clone(),
                 id,
                 msg_id,
                 ErrorCode::DatabaseError,
@@ -145,7 +133,7 @@ pub async fn get_repositories_handler(
     };
 
     WsServer::send_message(
-        clients.clone(),
+        ctx.clients.clone(),
         id,
         serde_json::to_string(&server_message).unwrap().as_str(),
     )
@@ -153,18 +141,17 @@ pub async fn get_repositories_handler(
 }
 
 pub async fn get_repository_handler(
-    clients: Arc<Mutex<HashMap<Uuid, Client>>>,
+    ctx: HandlerContext,
     id: Uuid,
-    pool: PgPool,
     msg_id: Option<String>,
     repo_id: i32,
 ) {
-    let repo = match db::Queries::get_repository(&pool, repo_id).await {
+    let repo = match db::Queries::get_repository(&ctx.pool, repo_id).await {
         Ok(repo) => repo,
         Err(e) => {
             error!("Failed to get repository: {}", e);
             WsServer::send_error_message(
-                clients.clone(),
+                ctx.clients.clone(),
                 id,
                 msg_id,
                 ErrorCode::DatabaseError,
@@ -184,7 +171,7 @@ pub async fn get_repository_handler(
     };
 
     WsServer::send_message(
-        clients.clone(),
+        ctx.clients.clone(),
         id,
         serde_json::to_string(&server_message).unwrap().as_str(),
     )
@@ -192,19 +179,36 @@ pub async fn get_repository_handler(
 }
 
 pub async fn update_repository_handler(
-    clients: Arc<Mutex<HashMap<Uuid, Client>>>,
+    ctx: HandlerContext,
     id: Uuid,
-    pool: PgPool,
     msg_id: Option<String>,
     repo_id: i32,
     is_active: Option<bool>,
     poll_interval_seconds: Option<i32>,
+    webhook_secret: Option<String>,
 ) {
+    if webhook_secret
+        .as_ref()
+        .is_some_and(|secret| secret.len() > 255)
+    {
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            msg_id,
+            ErrorCode::ValidationError,
+            "webhook_secret must be at most 255 characters",
+            None,
+        )
+        .await;
+        return;
+    }
+
     let repo = match db::Queries::update_repository(
-        &pool,
+        &ctx.pool,
         repo_id,
         is_active,
         poll_interval_seconds,
+        webhook_secret.as_deref(),
     )
     .await
     {
@@ -212,7 +216,7 @@ pub async fn update_repository_handler(
         Err(e) => {
             error!("Failed to update repository: {}", e);
             WsServer::send_error_message(
-                clients.clone(),
+                ctx.clients.clone(),
                 id,
                 msg_id,
                 ErrorCode::DatabaseError,
@@ -224,6 +228,8 @@ pub async fn update_repository_handler(
         }
     };
 
+    ctx.scheduler.repository_changed(repo.id);
+
     let server_message = ServerMessage {
         id: msg_id,
         payload: ServerMessageType::Success {
@@ -233,7 +239,7 @@ pub async fn update_repository_handler(
     };
 
     WsServer::send_message(
-        clients.clone(),
+        ctx.clients.clone(),
         id,
         serde_json::to_string(&server_message).unwrap().as_str(),
     )
@@ -241,19 +247,18 @@ pub async fn update_repository_handler(
 }
 
 pub async fn delete_repository_handler(
-    clients: Arc<Mutex<HashMap<Uuid, Client>>>,
+    ctx: HandlerContext,
     id: Uuid,
-    pool: PgPool,
     msg_id: Option<String>,
     repo_id: i32,
 ) {
-    if db::Queries::delete_repository(&pool, repo_id)
+    if db::Queries::delete_repository(&ctx.pool, repo_id)
         .await
         .is_err()
     {
         error!("Failed to delete repository");
         WsServer::send_error_message(
-            clients.clone(),
+            ctx.clients.clone(),
             id,
             msg_id,
             ErrorCode::DatabaseError,
@@ -264,6 +269,8 @@ pub async fn delete_repository_handler(
         return;
     };
 
+    ctx.scheduler.repository_removed(repo_id);
+
     let server_message = ServerMessage {
         id: msg_id,
         payload: ServerMessageType::Success {
@@ -273,7 +280,58 @@ pub async fn delete_repository_handler(
     };
 
     WsServer::send_message(
-        clients.clone(),
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+pub async fn get_polling_history_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    repo_id: i32,
+    limit: Option<i32>,
+) {
+    let limit = limit.unwrap_or(DEFAULT_POLLING_HISTORY_LIMIT);
+    if !(1..=MAX_POLLING_HISTORY_LIMIT).contains(&limit) {
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            msg_id,
+            ErrorCode::ValidationError,
+            &format!("limit must be between 1 and {}", MAX_POLLING_HISTORY_LIMIT),
+            None,
+        )
+        .await;
+        return;
+    }
+
+    let history = match db::Queries::get_polling_history(&ctx.pool, repo_id, limit as i64).await {
+        Ok(history) => history,
+        Err(e) => {
+            error!("Failed to get polling history: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to get polling history",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::PollingHistory { history },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
         id,
         serde_json::to_string(&server_message).unwrap().as_str(),
     )
@@ -0,0 +1,167 @@
+use crate::{
+    db,
+    ws::{
+        ws_message::{
+            ClientMessage, ClientMessageType, ErrorCode, ServerMessage, ServerMessageType,
+        },
+        ws_server::{HandlerContext, Subscriptions, WsServer},
+    },
+};
+use serde_json::json;
+use tracing::{error, info};
+use uuid::Uuid;
+
+pub async fn subscription_handler(ctx: HandlerContext, id: Uuid, msg: ClientMessage) {
+    match msg.payload {
+        ClientMessageType::Subscribe { repo_id } => {
+            subscribe_handler(ctx, id, msg.id, repo_id).await;
+        }
+        ClientMessageType::Unsubscribe { repo_id } => {
+            unsubscribe_handler(ctx, id, msg.id, repo_id).await;
+        }
+        ClientMessageType::GetSubscriptions => {
+            get_subscriptions_handler(ctx, id, msg.id).await;
+        }
+        _ => {
+            error!("Invalid message: {:?}", msg);
+        }
+    }
+}
+
+/// 저장소 이벤트 구독
+///
+/// `repo_id`가 없으면 전체 저장소 구독
+pub async fn subscribe_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    repo_id: Option<i32>,
+) {
+    if let Some(repo_id) = repo_id
+        && db::Queries::get_repository(&ctx.pool, repo_id)
+            .await
+            .is_err()
+    {
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            msg_id,
+            ErrorCode::NotFound,
+            "Repository not found",
+            None,
+        )
+        .await;
+        return;
+    }
+
+    let subscriptions = {
+        let mut guard = ctx.clients.lock().await;
+        let Some(client) = guard.get_mut(&id) else {
+            return;
+        };
+        match repo_id {
+            Some(repo_id) => {
+                client.subscriptions.repo_ids.insert(repo_id);
+            }
+            None => client.subscriptions.all = true,
+        }
+        subscriptions_value(&client.subscriptions)
+    }; // clients 락 해제
+    info!("Client {} subscribed: {:?}", id, repo_id);
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::Success {
+            message: "Subscribed successfully".to_string(),
+            data: Some(subscriptions),
+        },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+/// 저장소 이벤트 구독 해제
+///
+/// `repo_id`가 없으면 전체 구독과 저장소별 구독을 모두 해제
+pub async fn unsubscribe_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    repo_id: Option<i32>,
+) {
+    let subscriptions = {
+        let mut guard = ctx.clients.lock().await;
+        let Some(client) = guard.get_mut(&id) else {
+            return;
+        };
+        match repo_id {
+            Some(repo_id) => {
+                client.subscriptions.repo_ids.remove(&repo_id);
+            }
+            None => {
+                client.subscriptions.all = false;
+                client.subscriptions.repo_ids.clear();
+            }
+        }
+        subscriptions_value(&client.subscriptions)
+    }; // clients 락 해제
+    info!("Client {} unsubscribed: {:?}", id, repo_id);
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::Success {
+            message: "Unsubscribed successfully".to_string(),
+            data: Some(subscriptions),
+        },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+pub async fn get_subscriptions_handler(ctx: HandlerContext, id: Uuid, msg_id: Option<String>) {
+    let (all, repo_ids) = {
+        let guard = ctx.clients.lock().await;
+        let Some(client) = guard.get(&id) else {
+            return;
+        };
+        (
+            client.subscriptions.all,
+            sorted_repo_ids(&client.subscriptions),
+        )
+    }; // clients 락 해제
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::Subscriptions { all, repo_ids },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+fn subscriptions_value(subscriptions: &Subscriptions) -> serde_json::Value {
+    json!({
+        "all": subscriptions.all,
+        "repo_ids": sorted_repo_ids(subscriptions),
+    })
+}
+
+fn sorted_repo_ids(subscriptions: &Subscriptions) -> Vec<i32> {
+    let mut repo_ids: Vec<i32> = subscriptions.repo_ids.iter().copied().collect();
+    repo_ids.sort_unstable();
+    repo_ids
+}
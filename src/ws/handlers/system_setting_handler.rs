@@ -0,0 +1,165 @@
+use crate::{
+    db,
+    jenkins::{COMMAND_SETTING_KEYS, validate_command_name},
+    ws::{
+        ws_message::{
+            ClientMessage, ClientMessageType, ErrorCode, ServerMessage, ServerMessageType,
+        },
+        ws_server::{HandlerContext, WsServer},
+    },
+};
+use tracing::error;
+use uuid::Uuid;
+
+/// 초 단위 양의 정수여야 하는 설정
+const INTERVAL_SETTING_KEYS: [&str; 3] = [
+    "github_api_poll_interval",
+    "sync_refs_interval",
+    "webhook_reconcile_interval",
+];
+
+pub async fn system_setting_handler(ctx: HandlerContext, id: Uuid, msg: ClientMessage) {
+    match msg.payload {
+        ClientMessageType::UpdateSystemSetting { key, value } => {
+            update_system_setting_handler(ctx, id, msg.id, key, value).await;
+        }
+        ClientMessageType::GetSystemSettings => {
+            get_system_settings_handler(ctx, id, msg.id).await;
+        }
+        _ => {
+            error!("Invalid message: {:?}", msg);
+        }
+    }
+}
+
+pub async fn get_system_settings_handler(ctx: HandlerContext, id: Uuid, msg_id: Option<String>) {
+    let settings = match db::Queries::get_system_setting_list(&ctx.pool).await {
+        Ok(settings) => settings,
+        Err(e) => {
+            error!("Failed to get system settings: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to get system settings",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::SystemSettings { settings },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+pub async fn update_system_setting_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    key: String,
+    value: String,
+) {
+    let value = value.trim().to_string();
+    if INTERVAL_SETTING_KEYS.contains(&key.as_str())
+        && !value.parse::<u64>().is_ok_and(|secs| secs > 0)
+    {
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            msg_id,
+            ErrorCode::ValidationError,
+            &format!("{} must be a positive number of seconds", key),
+            None,
+        )
+        .await;
+        return;
+    }
+
+    if COMMAND_SETTING_KEYS.contains(&key.as_str())
+        && let Err(e) = validate_command_name(&value)
+    {
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            msg_id,
+            ErrorCode::ValidationError,
+            &format!("{} {}", key, e),
+            None,
+        )
+        .await;
+        return;
+    }
+
+    let setting = match db::Queries::update_system_setting(&ctx.pool, &key, &value).await {
+        Ok(Some(setting)) => setting,
+        Ok(None) => {
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::NotFound,
+                "System setting not found",
+                None,
+            )
+            .await;
+            return;
+        }
+        Err(e) => {
+            error!("Failed to update system setting: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to update system setting",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    match setting.key.as_str() {
+        "github_api_poll_interval" => ctx
+            .scheduler
+            .default_interval_changed(setting.value.parse().unwrap()),
+        "webhook_reconcile_interval" => ctx
+            .scheduler
+            .reconcile_interval_changed(setting.value.parse().unwrap()),
+        _ => {}
+    }
+
+    let changed_by = WsServer::identity(&ctx.clients, id)
+        .await
+        .map_or_else(|| id.to_string(), |identity| identity.name);
+    ctx.events.publish(ServerMessageType::SettingsChanged {
+        changed_by,
+        keys: vec![setting.key.clone()],
+    });
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::Success {
+            message: "System setting updated successfully".to_string(),
+            data: Some(serde_json::to_value(&setting).unwrap()),
+        },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
@@ -0,0 +1,361 @@
+use crate::{
+    db,
+    rules::Pattern,
+    ws::{
+        handlers::jenkins_handler::validate_job_name,
+        ws_message::{
+            ClientMessage, ClientMessageType, ErrorCode, ServerMessage, ServerMessageType,
+        },
+        ws_server::{HandlerContext, WsServer},
+    },
+};
+use serde_json::{Map, Value};
+use tracing::error;
+use uuid::Uuid;
+
+pub async fn tag_handler(ctx: HandlerContext, id: Uuid, msg: ClientMessage) {
+    match msg.payload {
+        ClientMessageType::AddTagRule {
+            repo_id,
+            mapping_id,
+            pattern,
+            jenkins_job_name,
+            parameters,
+        } => {
+            add_tag_rule_handler(
+                ctx,
+                id,
+                msg.id,
+                repo_id,
+                mapping_id,
+                pattern,
+                jenkins_job_name,
+                parameters,
+            )
+            .await;
+        }
+        ClientMessageType::DeleteTagRule { rule_id } => {
+            delete_tag_rule_handler(ctx, id, msg.id, rule_id).await;
+        }
+        ClientMessageType::GetTags { repo_id } => {
+            get_tags_handler(ctx, id, msg.id, repo_id).await;
+        }
+        ClientMessageType::GetTagBuildHistory { tag_id } => {
+            get_tag_build_history_handler(ctx, id, msg.id, tag_id).await;
+        }
+        ClientMessageType::GetTagRules { repo_id } => {
+            get_tag_rules_handler(ctx, id, msg.id, repo_id).await;
+        }
+        _ => {
+            error!("Invalid message: {:?}", msg);
+        }
+    }
+}
+
+/// 태그 규칙 추가
+///
+/// 이후 패턴에 맞는 태그가 새로 생기면 지정한 job을 파라미터와 함께 빌드.
+/// Jenkins 서버와 커밋 상태 context는 규칙에 저장한 매핑을 따르며, 매핑을 지정하지
+/// 않으면 자동 트리거가 켜진 매핑 중 id가 가장 작은 매핑을 저장 (없으면 거부)
+#[allow(clippy::too_many_arguments)]
+pub async fn add_tag_rule_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    repo_id: i32,
+    mapping_id: Option<i32>,
+    pattern: String,
+    jenkins_job_name: String,
+    parameters: Option<Value>,
+) {
+    let pattern = pattern.trim();
+    let jenkins_job_name = jenkins_job_name.trim().trim_matches('/');
+    let parameters = parameters.unwrap_or_else(|| Value::Object(Map::new()));
+
+    let validation = Pattern::new(pattern)
+        .map_err(|e| e.to_string())
+        .and_then(|_| validate_job_name(jenkins_job_name))
+        .and_then(|_| validate_parameters(&parameters));
+    if let Err(reason) = validation {
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            msg_id,
+            ErrorCode::ValidationError,
+            &reason,
+            None,
+        )
+        .await;
+        return;
+    }
+
+    if db::Queries::get_repository(&ctx.pool, repo_id)
+        .await
+        .is_err()
+    {
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            msg_id,
+            ErrorCode::NotFound,
+            "Repository not found",
+            None,
+        )
+        .await;
+        return;
+    }
+
+    let mapping = match db::Queries::get_jenkins_mappings(&ctx.pool, repo_id).await {
+        Ok(mappings) => mappings.into_iter().find(|mapping| match mapping_id {
+            Some(mapping_id) => mapping.id == mapping_id,
+            None => mapping.auto_trigger,
+        }),
+        Err(e) => {
+            error!("Failed to get Jenkins mappings: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to get Jenkins mappings",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+    let Some(mapping) = mapping else {
+        let (code, message) = match mapping_id {
+            Some(_) => (ErrorCode::NotFound, "Jenkins mapping not found"),
+            None => (
+                ErrorCode::ValidationError,
+                "Repository has no Jenkins mapping with auto_trigger enabled",
+            ),
+        };
+        WsServer::send_error_message(ctx.clients.clone(), id, msg_id, code, message, None).await;
+        return;
+    };
+
+    let rule = match db::Queries::create_tag_rule(
+        &ctx.pool,
+        repo_id,
+        mapping.id,
+        pattern,
+        jenkins_job_name,
+        &parameters,
+    )
+    .await
+    {
+        Ok(Some(rule)) => rule,
+        Ok(None) => {
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::AlreadyExists,
+                "Tag rule already exists",
+                None,
+            )
+            .await;
+            return;
+        }
+        Err(e) => {
+            error!("Failed to create tag rule: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to create tag rule",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::Success {
+            message: "Tag rule added successfully".to_string(),
+            data: Some(serde_json::to_value(&rule).unwrap()),
+        },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+pub async fn delete_tag_rule_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    rule_id: i32,
+) {
+    let rule = match db::Queries::delete_tag_rule(&ctx.pool, rule_id).await {
+        Ok(Some(rule)) => rule,
+        Ok(None) => {
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::NotFound,
+                "Tag rule not found",
+                None,
+            )
+            .await;
+            return;
+        }
+        Err(e) => {
+            error!("Failed to delete tag rule: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to delete tag rule",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::Success {
+            message: "Tag rule deleted successfully".to_string(),
+            data: Some(serde_json::to_value(&rule).unwrap()),
+        },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+pub async fn get_tags_handler(ctx: HandlerContext, id: Uuid, msg_id: Option<String>, repo_id: i32) {
+    let tags = match db::Queries::get_tags(&ctx.pool, repo_id).await {
+        Ok(tags) => tags,
+        Err(e) => {
+            error!("Failed to get tags: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to get tags",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::Tags { tags },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+pub async fn get_tag_build_history_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    tag_id: i32,
+) {
+    let builds = match db::Queries::get_tag_build_triggers(&ctx.pool, tag_id).await {
+        Ok(builds) => builds,
+        Err(e) => {
+            error!("Failed to get tag build history: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to get tag build history",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::BuildHistory { builds },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+pub async fn get_tag_rules_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    repo_id: i32,
+) {
+    let rules = match db::Queries::get_tag_rules(&ctx.pool, repo_id).await {
+        Ok(rules) => rules,
+        Err(e) => {
+            error!("Failed to get tag rules: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to get tag rules",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::TagRules { rules },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+/// 빌드 파라미터 검증 (이름이 비어 있지 않고 값이 문자열인 객체)
+pub(crate) fn validate_parameters(parameters: &Value) -> Result<(), String> {
+    let Some(parameters) = parameters.as_object() else {
+        return Err("parameters must be an object".to_string());
+    };
+    for (name, value) in parameters {
+        if name.trim().is_empty() || name.len() > 255 {
+            return Err("parameter names must be between 1 and 255 characters".to_string());
+        }
+        if !value.is_string() {
+            return Err(format!("parameter {:?} must be a string", name));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use crate::{
+    db,
+    webhook::InboundDelivery,
+    ws::{
+        ws_message::{
+            ClientMessage, ClientMessageType, ErrorCode, ServerMessage, ServerMessageType,
+        },
+        ws_server::{HandlerContext, WsServer},
+    },
+};
+use tracing::{error, info};
+use uuid::Uuid;
+
+/// 웹훅 전송 기록 기본 조회 개수
+const DEFAULT_WEBHOOK_DELIVERY_LIMIT: i32 = 50;
+
+/// 웹훅 전송 기록 최대 조회 개수 (본문 포함)
+const MAX_WEBHOOK_DELIVERY_LIMIT: i32 = 500;
+
+/// `webhook_deliveries.outcome` 허용 값
+const DELIVERY_OUTCOMES: [&str; 4] = ["processed", "ignored", "rejected", "failed"];
+
+pub async fn webhook_handler(ctx: HandlerContext, id: Uuid, msg: ClientMessage) {
+    match msg.payload {
+        ClientMessageType::ReplayWebhookDelivery { delivery_id } => {
+            replay_webhook_delivery_handler(ctx, id, msg.id, delivery_id).await;
+        }
+        ClientMessageType::GetWebhookDeliveries {
+            repo_id,
+            event,
+            outcome,
+            limit,
+        } => {
+            get_webhook_deliveries_handler(ctx, id, msg.id, repo_id, event, outcome, limit).await;
+        }
+        _ => {
+            error!("Invalid message: {:?}", msg);
+        }
+    }
+}
+
+/// 저장된 웹훅 전송 재처리
+///
+/// 원본 기록은 유지하고 재처리 결과를 `replay_of`가 지정된 새 기록으로 저장
+pub async fn replay_webhook_delivery_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    delivery_id: i32,
+) {
+    let original = match db::Queries::get_webhook_delivery(&ctx.pool, delivery_id).await {
+        Ok(Some(original)) => original,
+        Ok(None) => {
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::NotFound,
+                "Webhook delivery not found",
+                None,
+            )
+            .await;
+            return;
+        }
+        Err(e) => {
+            error!("Failed to get webhook delivery: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to get webhook delivery",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    info!("Replaying webhook delivery {} by {}", original.id, id);
+    let inbound = InboundDelivery {
+        delivery_guid: original.delivery_guid.as_deref(),
+        event: original.event.as_deref(),
+        headers: &original.headers,
+        body: &original.body,
+        replay_of: Some(original.id),
+    };
+    let Some(delivery) = ctx.webhooks.receive(&inbound).await.delivery else {
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            msg_id,
+            ErrorCode::DatabaseError,
+            "Failed to record webhook delivery",
+            None,
+        )
+        .await;
+        return;
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::Success {
+            message: "Webhook delivery replayed".to_string(),
+            data: Some(serde_json::to_value(&delivery).unwrap()),
+        },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
+
+pub async fn get_webhook_deliveries_handler(
+    ctx: HandlerContext,
+    id: Uuid,
+    msg_id: Option<String>,
+    repo_id: Option<i32>,
+    event: Option<String>,
+    outcome: Option<String>,
+    limit: Option<i32>,
+) {
+    let limit = limit.unwrap_or(DEFAULT_WEBHOOK_DELIVERY_LIMIT);
+    let validation = if !(1..=MAX_WEBHOOK_DELIVERY_LIMIT).contains(&limit) {
+        Err(format!(
+            "limit must be between 1 and {}",
+            MAX_WEBHOOK_DELIVERY_LIMIT
+        ))
+    } else if outcome
+        .as_deref()
+        .is_some_and(|outcome| !DELIVERY_OUTCOMES.contains(&outcome))
+    {
+        Err(format!(
+            "outcome must be one of {}",
+            DELIVERY_OUTCOMES.join(", ")
+        ))
+    } else {
+        Ok(())
+    };
+    if let Err(reason) = validation {
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            msg_id,
+            ErrorCode::ValidationError,
+            &reason,
+            None,
+        )
+        .await;
+        return;
+    }
+
+    let deliveries = match db::Queries::get_webhook_deliveries(
+        &ctx.pool,
+        repo_id,
+        event.as_deref(),
+        outcome.as_deref(),
+        limit as i64,
+    )
+    .await
+    {
+        Ok(deliveries) => deliveries,
+        Err(e) => {
+            error!("Failed to get webhook deliveries: {}", e);
+            WsServer::send_error_message(
+                ctx.clients.clone(),
+                id,
+                msg_id,
+                ErrorCode::DatabaseError,
+                "Failed to get webhook deliveries",
+                None,
+            )
+            .await;
+            return;
+        }
+    };
+
+    let server_message = ServerMessage {
+        id: msg_id,
+        payload: ServerMessageType::WebhookDeliveries { deliveries },
+    };
+
+    WsServer::send_message(
+        ctx.clients.clone(),
+        id,
+        serde_json::to_string(&server_message).unwrap().as_str(),
+    )
+    .await;
+}
@@ -1,3 +1,5 @@
-pub mod ws_server;
+pub mod audit;
+pub mod event_bus;
+pub mod handlers;
 pub mod ws_message;
-pub mod handlers;
\ No newline at end of file
+pub mod ws_server;
@@ -1,3 +1,7 @@
+use crate::db::{
+    ApiToken, AuditLog, Branch, BranchWatchRule, BuildTrigger, JenkinsMapping, PullRequest,
+    PullRequestTransition, RepositoryPollingHistory, SystemSetting, Tag, TagRule, WebhookDelivery,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -36,6 +40,23 @@ pub struct ServerMessage {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum ClientMessageType {
+    // -------------------------------------------------------------------------
+    // 인증
+    // -------------------------------------------------------------------------
+    /// API 토큰으로 연결 인증 (업그레이드 요청에 Bearer 헤더가 없는 경우)
+    Authenticate { token: String },
+
+    /// API 토큰 발급 (토큰 원문은 응답으로 한 번만 전달)
+    CreateApiToken {
+        name: String,
+        /// "viewer", "operator", "admin" (기본: "viewer")
+        #[serde(skip_serializing_if = "Option::is_none")]
+        role: Option<String>,
+    },
+
+    /// API 토큰 폐기
+    RevokeApiToken { token_id: i32 },
+
     // -------------------------------------------------------------------------
     // 저장소 관리
     // -------------------------------------------------------------------------
@@ -54,6 +75,9 @@ pub enum ClientMessageType {
         is_active: Option<bool>,
         #[serde(skip_serializing_if = "Option::is_none")]
         poll_interval_seconds: Option<i32>,
+        /// 웹훅 서명 비밀값 (빈 문자열이면 해제)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        webhook_secret: Option<String>,
     },
 
     /// 저장소 삭제
@@ -63,21 +87,113 @@ pub enum ClientMessageType {
     // Jenkins 연동 설정
     // -------------------------------------------------------------------------
     /// Jenkins 매핑 추가/수정
+    ///
+    /// 조건 목록은 비어 있으면 조건 없음이며, 지정된 조건을 모두 만족하는 PR만 트리거
     SetJenkinsMapping {
         repo_id: i32,
+        /// 수정할 매핑 (없으면 새 매핑 추가)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        mapping_id: Option<i32>,
         jenkins_job_name: String,
         jenkins_url: String,
         auto_trigger: bool,
+        /// GitHub 커밋 상태 context 이름 (기본: "pr-bridge/jenkins", 저장소 내 매핑마다 고유)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        status_context: Option<String>,
+        /// PR 대상 브랜치 glob 패턴 (예: ["main", "release/*"])
+        #[serde(skip_serializing_if = "Option::is_none")]
+        target_branch_patterns: Option<Vec<String>>,
+        /// PR source 브랜치 glob 패턴
+        #[serde(skip_serializing_if = "Option::is_none")]
+        source_branch_patterns: Option<Vec<String>>,
+        /// 포함할 변경 파일 경로 glob 패턴 (예: ["services/api/**"])
+        #[serde(skip_serializing_if = "Option::is_none")]
+        path_patterns: Option<Vec<String>>,
+        /// 제외할 변경 파일 경로 glob 패턴 (예: ["docs/**", "**/*.md"])
+        #[serde(skip_serializing_if = "Option::is_none")]
+        excluded_path_patterns: Option<Vec<String>>,
+        /// 하나라도 붙어 있어야 하는 PR 라벨
+        #[serde(skip_serializing_if = "Option::is_none")]
+        required_labels: Option<Vec<String>>,
+        /// 하나라도 붙어 있으면 트리거하지 않는 PR 라벨 (예: ["wip"])
+        #[serde(skip_serializing_if = "Option::is_none")]
+        excluded_labels: Option<Vec<String>>,
+        /// Jenkins 빌드 파라미터 템플릿 (예: {"PR_REF": "pull/{{pr_number}}/head"})
+        #[serde(skip_serializing_if = "Option::is_none")]
+        parameters: Option<Value>,
+        /// 새 커밋 감지 후 자동 빌드 전 대기 시간 (초, 0~3600)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        debounce_seconds: Option<i32>,
+        /// 초안 PR 자동 빌드 건너뛰기
+        #[serde(skip_serializing_if = "Option::is_none")]
+        skip_draft: Option<bool>,
+        /// 커밋 메시지에 `[skip ci]`가 있으면 자동 빌드 건너뛰기
+        #[serde(skip_serializing_if = "Option::is_none")]
+        skip_ci_commits: Option<bool>,
+        /// 새 커밋 빌드 시 같은 PR의 진행 중인 이전 빌드 중단
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cancel_previous: Option<bool>,
     },
 
     /// Jenkins 매핑 삭제
-    DeleteJenkinsMapping { repo_id: i32 },
+    DeleteJenkinsMapping { mapping_id: i32 },
+
+    /// 브랜치 감시 규칙 추가 (이름 또는 glob 패턴, 예: "release/*")
+    AddBranchWatchRule { repo_id: i32, pattern: String },
+
+    /// 브랜치 감시 규칙 삭제
+    DeleteBranchWatchRule { rule_id: i32 },
+
+    /// 태그 규칙 추가 (패턴에 맞는 새 태그를 지정한 job으로 빌드, 예: "v*")
+    AddTagRule {
+        repo_id: i32,
+        /// 빌드에 사용할 Jenkins 매핑 (없으면 자동 트리거가 켜진 매핑 중 id가 가장 작은 매핑)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        mapping_id: Option<i32>,
+        pattern: String,
+        jenkins_job_name: String,
+        /// Jenkins 빌드 파라미터 (문자열 값만 갖는 객체)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        parameters: Option<Value>,
+    },
+
+    /// 태그 규칙 삭제
+    DeleteTagRule { rule_id: i32 },
 
     // -------------------------------------------------------------------------
     // 빌드 제어
     // -------------------------------------------------------------------------
     /// 수동 빌드 트리거
-    TriggerBuild { pr_id: i32 },
+    TriggerBuild {
+        pr_id: i32,
+        /// 빌드할 매핑 (없으면 트리거 조건에 맞는 모든 매핑)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        mapping_id: Option<i32>,
+        /// 이미 트리거된 커밋이라도 재빌드 (시도 번호 증가)
+        #[serde(default)]
+        force: bool,
+    },
+
+    // -------------------------------------------------------------------------
+    // 웹훅
+    // -------------------------------------------------------------------------
+    /// 저장된 웹훅 전송 재처리 (서명은 현재 비밀값으로 다시 검증)
+    ReplayWebhookDelivery { delivery_id: i32 },
+
+    // -------------------------------------------------------------------------
+    // 이벤트 구독
+    // -------------------------------------------------------------------------
+    /// 저장소 이벤트 구독 (`repo_id` 생략 시 전체 저장소)
+    Subscribe {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        repo_id: Option<i32>,
+    },
+
+    /// 저장소 이벤트 구독 해제 (`repo_id` 생략 시 모든 구독 해제)
+    Unsubscribe {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        repo_id: Option<i32>,
+    },
 
     // -------------------------------------------------------------------------
     // 시스템 설정
@@ -98,12 +214,36 @@ pub enum ClientMessageType {
     GetPullRequests {
         repo_id: i32,
         #[serde(skip_serializing_if = "Option::is_none")]
-        status: Option<String>, // "open", "closed", "merged"
+        status: Option<String>, // "open", "draft", "closed", "merged", "reopened"
     },
 
+    /// PR 상태 전이 이력 조회
+    GetPullRequestHistory { pr_id: i32 },
+
     /// 빌드 이력 조회
     GetBuildHistory { pr_id: i32 },
 
+    /// 저장소의 Jenkins 매핑 목록 조회
+    GetJenkinsMappings { repo_id: i32 },
+
+    /// 브랜치 목록 조회
+    GetBranches { repo_id: i32 },
+
+    /// 브랜치 빌드 이력 조회
+    GetBranchBuildHistory { branch_id: i32 },
+
+    /// 브랜치 감시 규칙 조회
+    GetBranchWatchRules { repo_id: i32 },
+
+    /// 태그 목록 조회
+    GetTags { repo_id: i32 },
+
+    /// 태그 빌드 이력 조회
+    GetTagBuildHistory { tag_id: i32 },
+
+    /// 태그 규칙 조회
+    GetTagRules { repo_id: i32 },
+
     /// 시스템 설정 조회
     GetSystemSettings,
 
@@ -113,6 +253,40 @@ pub enum ClientMessageType {
         #[serde(skip_serializing_if = "Option::is_none")]
         limit: Option<i32>,
     },
+
+    /// 현재 연결의 구독 목록 조회
+    GetSubscriptions,
+
+    /// API 토큰 목록 조회
+    GetApiTokens,
+
+    /// 감사 로그 조회 (최신순)
+    GetAuditLog {
+        /// API 토큰 이름
+        #[serde(skip_serializing_if = "Option::is_none")]
+        actor: Option<String>,
+        /// 조회 시작 시각 (포함, ISO 8601)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        since: Option<String>,
+        /// 조회 종료 시각 (미포함, ISO 8601)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        until: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limit: Option<i32>,
+    },
+
+    /// 웹훅 전송 기록 조회 (최신순)
+    GetWebhookDeliveries {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        repo_id: Option<i32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        event: Option<String>,
+        /// "processed", "ignored", "rejected", "failed"
+        #[serde(skip_serializing_if = "Option::is_none")]
+        outcome: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limit: Option<i32>,
+    },
 }
 
 // =============================================================================
@@ -132,16 +306,54 @@ pub enum ServerMessageType {
     Repository { repository: Value },
 
     /// PR 목록
-    PullRequests { pull_requests: Vec<Value> },
+    PullRequests { pull_requests: Vec<PullRequest> },
+
+    /// PR 상태 전이 이력
+    PullRequestHistory {
+        transitions: Vec<PullRequestTransition>,
+    },
 
     /// 빌드 이력
-    BuildHistory { builds: Vec<Value> },
+    BuildHistory { builds: Vec<BuildTrigger> },
+
+    /// Jenkins 매핑 목록
+    JenkinsMappings { mappings: Vec<JenkinsMapping> },
+
+    /// 브랜치 목록
+    Branches { branches: Vec<Branch> },
+
+    /// 브랜치 감시 규칙 목록
+    BranchWatchRules { rules: Vec<BranchWatchRule> },
+
+    /// 태그 목록
+    Tags { tags: Vec<Tag> },
+
+    /// 태그 규칙 목록
+    TagRules { rules: Vec<TagRule> },
 
     /// 시스템 설정
-    SystemSettings { settings: Value },
+    SystemSettings { settings: Vec<SystemSetting> },
 
     /// 폴링 이력
-    PollingHistory { history: Vec<Value> },
+    PollingHistory {
+        history: Vec<RepositoryPollingHistory>,
+    },
+
+    /// API 토큰 목록
+    ApiTokens { tokens: Vec<ApiToken> },
+
+    /// 감사 로그
+    AuditLog { entries: Vec<AuditLog> },
+
+    /// 웹훅 전송 기록
+    WebhookDeliveries { deliveries: Vec<WebhookDelivery> },
+
+    /// 구독 목록
+    Subscriptions {
+        /// 전체 저장소 구독 여부
+        all: bool,
+        repo_ids: Vec<i32>,
+    },
 
     /// 성공 응답
     Success {
@@ -162,13 +374,15 @@ pub enum ServerMessageType {
         head_sha: String,
     },
 
-    /// 새 PR 감지
+    /// 새 PR 감지 또는 닫혔던 PR이 다시 열림
     PrOpened {
         repo_id: i32,
         pr_number: i32,
         pr_id: i32,
         title: String,
         author: Option<String>,
+        /// 닫혔던 PR이 다시 열린 경우
+        reopened: bool,
     },
 
     /// PR 닫힘
@@ -179,9 +393,12 @@ pub enum ServerMessageType {
         merged: bool,
     },
 
-    /// 빌드 트리거 성공
+    /// 빌드 트리거 성공 (PR 빌드는 `pr_id`, 브랜치 빌드는 `branch_id` 설정)
     BuildTriggered {
-        pr_id: i32,
+        repo_id: i32,
+        pr_id: Option<i32>,
+        branch_id: Option<i32>,
+        tag_id: Option<i32>,
         commit_sha: String,
         jenkins_build_number: Option<i32>,
         jenkins_build_url: Option<String>,
@@ -189,11 +406,28 @@ pub enum ServerMessageType {
 
     /// 빌드 트리거 실패
     BuildTriggerFailed {
-        pr_id: i32,
+        repo_id: i32,
+        pr_id: Option<i32>,
+        branch_id: Option<i32>,
+        tag_id: Option<i32>,
         commit_sha: String,
         error: String,
     },
 
+    /// 빌드 완료
+    BuildCompleted {
+        repo_id: i32,
+        pr_id: Option<i32>,
+        branch_id: Option<i32>,
+        tag_id: Option<i32>,
+        commit_sha: String,
+        jenkins_build_number: Option<i32>,
+        jenkins_build_url: Option<String>,
+        result: String, // "success", "failure", "unstable", "aborted"
+        #[serde(skip_serializing_if = "Option::is_none")]
+        duration_ms: Option<i64>,
+    },
+
     /// 브랜치 동기화 완료
     BranchesSynced {
         repo_id: i32,
@@ -203,7 +437,12 @@ pub enum ServerMessageType {
     },
 
     /// 태그 동기화 완료
-    TagsSynced { repo_id: i32, added: i32 },
+    TagsSynced {
+        repo_id: i32,
+        added: i32,
+        updated: i32,
+        deleted: i32,
+    },
 
     /// 폴링 완료
     PollingCompleted {
@@ -235,6 +474,38 @@ pub enum ServerMessageType {
     Pong,
 }
 
+impl ClientMessageType {
+    /// 메시지 타입 이름 (`type` 필드 값)
+    ///
+    /// 토큰 등 비밀값이 남지 않도록 로그에는 메시지 본문 대신 이 값만 기록
+    pub fn type_name(&self) -> String {
+        serde_json::to_value(self).unwrap()["type"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string()
+    }
+}
+
+impl ServerMessageType {
+    /// 이벤트가 속한 저장소 ID (구독 필터링용)
+    ///
+    /// 저장소와 무관한 메시지는 None
+    pub fn repo_id(&self) -> Option<i32> {
+        match self {
+            ServerMessageType::PrUpdated { repo_id, .. }
+            | ServerMessageType::PrOpened { repo_id, .. }
+            | ServerMessageType::PrClosed { repo_id, .. }
+            | ServerMessageType::BuildTriggered { repo_id, .. }
+            | ServerMessageType::BuildTriggerFailed { repo_id, .. }
+            | ServerMessageType::BuildCompleted { repo_id, .. }
+            | ServerMessageType::BranchesSynced { repo_id, .. }
+            | ServerMessageType::TagsSynced { repo_id, .. }
+            | ServerMessageType::PollingCompleted { repo_id, .. } => Some(*repo_id),
+            _ => None,
+        }
+    }
+}
+
 // =============================================================================
 // 에러 코드
 // =============================================================================
@@ -251,6 +522,9 @@ pub enum ErrorCode {
     /// 권한 없음
     Unauthorized,
 
+    /// 역할 권한 부족
+    Forbidden,
+
     /// DB 에러
     DatabaseError,
 
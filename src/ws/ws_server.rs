@@ -1,15 +1,46 @@
+use crate::auth;
+use crate::db::Role;
+use crate::jenkins::BuildTriggerService;
+use crate::scheduler::SchedulerHandle;
+use crate::webhook::WebhookProcessor;
+use crate::ws::audit::PendingAudit;
+use crate::ws::event_bus::EventBus;
+use crate::ws::handlers::audit_handler::audit_handler;
+use crate::ws::handlers::auth_handler::auth_handler;
+use crate::ws::handlers::branch_handler::branch_handler;
+use crate::ws::handlers::build_handler::build_handler;
+use crate::ws::handlers::jenkins_handler::jenkins_handler;
+use crate::ws::handlers::pull_request_handler::pull_request_handler;
 use crate::ws::handlers::repository_handler::repository_handler;
+use crate::ws::handlers::subscription_handler::subscription_handler;
+use crate::ws::handlers::system_setting_handler::system_setting_handler;
+use crate::ws::handlers::tag_handler::tag_handler;
+use crate::ws::handlers::webhook_handler::webhook_handler;
 use crate::ws::ws_message::{
     ClientMessage, ClientMessageType, ErrorCode, ServerMessage, ServerMessageType,
 };
 use futures_util::{SinkExt, StreamExt};
 use serde_json::{self, Value};
 use sqlx::PgPool;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    net::SocketAddr,
+    sync::Arc,
+};
 use tokio::net::TcpListener;
-use tokio::{net::TcpStream, sync::Mutex};
-use tokio_tungstenite::{WebSocketStream, accept_async, tungstenite::Message};
-use tracing::{error, info};
+use tokio::{
+    net::TcpStream,
+    sync::{Mutex, broadcast},
+};
+use tokio_tungstenite::{
+    WebSocketStream, accept_hdr_async,
+    tungstenite::{
+        Message,
+        handshake::server::{Request, Response},
+        http::header::AUTHORIZATION,
+    },
+};
+use tracing::{error, info, warn};
 use uuid::Uuid;
 
 type WebSocketWriter = futures_util::stream::SplitSink<WebSocketStream<TcpStream>, Message>;
@@ -18,33 +49,100 @@ type WebSocketWriter = futures_util::stream::SplitSink<WebSocketStream<TcpStream
 pub struct Client {
     /// WebSocket 쓰기 스트림
     pub write: Arc<Mutex<WebSocketWriter>>,
+
+    /// 원격 주소
+    pub addr: SocketAddr,
+
+    /// 인증된 주체 (미인증 연결은 조회만 가능)
+    pub identity: Option<auth::Identity>,
+
+    /// 저장소 이벤트 구독 상태
+    pub subscriptions: Subscriptions,
+}
+
+/// 클라이언트의 저장소 이벤트 구독 상태
+///
+/// 구독하지 않은 저장소의 이벤트는 전송하지 않음
+#[derive(Debug, Clone, Default)]
+pub struct Subscriptions {
+    /// 전체 저장소 구독 (와일드카드)
+    pub all: bool,
+    pub repo_ids: HashSet<i32>,
+}
+
+impl Subscriptions {
+    pub fn matches(&self, repo_id: i32) -> bool {
+        self.all || self.repo_ids.contains(&repo_id)
+    }
+}
+
+/// 메시지 핸들러에 전달되는 서버 공유 자원
+#[derive(Clone)]
+pub struct HandlerContext {
+    pub clients: Arc<Mutex<HashMap<Uuid, Client>>>,
+    pub pool: PgPool,
+    pub scheduler: SchedulerHandle,
+    pub triggers: BuildTriggerService,
+    pub webhooks: WebhookProcessor,
+    pub events: EventBus,
 }
 
 pub struct WsServer {
     clients: Arc<Mutex<HashMap<Uuid, Client>>>,
     pool: PgPool,
+    scheduler: SchedulerHandle,
+    triggers: BuildTriggerService,
+    webhooks: WebhookProcessor,
+    events: EventBus,
 }
 
 impl WsServer {
-    pub fn new(pool: &PgPool) -> Self {
+    pub fn new(
+        pool: &PgPool,
+        scheduler: SchedulerHandle,
+        triggers: BuildTriggerService,
+        webhooks: WebhookProcessor,
+        events: EventBus,
+    ) -> Self {
         WsServer {
             clients: Arc::new(Mutex::new(HashMap::new())),
             pool: pool.clone(),
+            scheduler,
+            triggers,
+            webhooks,
+            events,
         }
     }
 
-    pub async fn run(&self, port: u16) {
-        let addr = format!("0.0.0.0:{}", port);
+    pub async fn run(&self, addr: &str) {
         info!("WebSocket Server Start: {}", addr);
         let listener = TcpListener::bind(addr).await.unwrap();
 
+        // 이벤트 버스 구독 및 전체 클라이언트 전송 시작
+        tokio::spawn(WsServer::forward_events(
+            self.clients.clone(),
+            self.events.subscribe(),
+        ));
+
         while let Ok((stream, addr)) = listener.accept().await {
-            // WebSocket 연결로 업그레이드
-            let ws_stream = match accept_async(stream).await {
+            // WebSocket 연결로 업그레이드 (Authorization 헤더의 Bearer 토큰 확인)
+            let mut bearer_token = None;
+            // 핸드셰이크 콜백 시그니처가 tungstenite에 고정되어 있음
+            #[allow(clippy::result_large_err)]
+            let read_bearer_token = |request: &Request, response: Response| {
+                bearer_token = request
+                    .headers()
+                    .get(AUTHORIZATION)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(auth::bearer_token)
+                    .map(str::to_string);
+                Ok(response)
+            };
+            let ws_stream = match accept_hdr_async(stream, read_bearer_token).await {
                 Ok(ws_stream) => ws_stream,
                 Err(e) => {
-                    error!("WebSocket upgrade failed: {}", e);
-                    break;
+                    error!("WebSocket upgrade failed: {}, {}", addr, e);
+                    continue;
                 }
             };
 
@@ -56,13 +154,30 @@ impl WsServer {
                 id,
                 Client {
                     write: Arc::new(Mutex::new(write)),
+                    addr,
+                    identity: None,
+                    subscriptions: Subscriptions::default(),
                 },
             );
 
             // 클라이언트별 메시지 처리 태스크 시작
-            let pool = self.pool.clone();
             let clients = self.clients.clone();
+            let ctx = HandlerContext {
+                clients: self.clients.clone(),
+                pool: self.pool.clone(),
+                scheduler: self.scheduler.clone(),
+                triggers: self.triggers.clone(),
+                webhooks: self.webhooks.clone(),
+                events: self.events.clone(),
+            };
             tokio::spawn(async move {
+                if let Some(token) = bearer_token
+                    && !WsServer::authenticate_connection(&ctx, id, &token).await
+                {
+                    WsServer::close_connection(clients, id).await;
+                    return;
+                }
+
                 let mut read_stream = read;
                 loop {
                     let msg = match read_stream.next().await {
@@ -107,11 +222,10 @@ impl WsServer {
                         Message::Frame(_) => continue,
                     };
 
-                    info!("Received message: {}", msg_text);
                     let msg_parsed = match serde_json::from_str::<ClientMessage>(&msg_text) {
                         Ok(msg) => msg,
                         Err(e) => {
-                            error!("Message parse to json error: {}, {}", e, msg_text);
+                            error!("Message parse to json error from {}: {}", id, e);
                             WsServer::send_message(
                                 clients.clone(),
                                 id,
@@ -124,23 +238,181 @@ impl WsServer {
                         }
                     };
 
+                    info!(
+                        "Received message from {}: {}",
+                        id,
+                        msg_parsed.payload.type_name()
+                    );
+
+                    if let Some(required) = auth::required_role(&msg_parsed.payload)
+                        && let Err((code, reason)) =
+                            WsServer::authorize(&clients, id, required).await
+                    {
+                        WsServer::send_error_message(
+                            clients.clone(),
+                            id,
+                            msg_parsed.id,
+                            code,
+                            &reason,
+                            None,
+                        )
+                        .await;
+                        continue;
+                    }
+
+                    // 설정 변경 명령은 핸들러 실행 전후 스냅샷을 감사 로그에 기록
+                    let audit = PendingAudit::begin(&ctx.pool, &msg_parsed.payload).await;
+
                     match msg_parsed.payload {
+                        ClientMessageType::Authenticate { .. }
+                        | ClientMessageType::CreateApiToken { .. }
+                        | ClientMessageType::RevokeApiToken { .. }
+                        | ClientMessageType::GetApiTokens => {
+                            auth_handler(ctx.clone(), id, msg_parsed).await;
+                        }
                         ClientMessageType::AddRepository { .. }
                         | ClientMessageType::UpdateRepository { .. }
                         | ClientMessageType::DeleteRepository { .. }
-                        | ClientMessageType::GetRepositories { .. }
-                        | ClientMessageType::GetRepository { .. } => {
-                            repository_handler(clients.clone(), id, pool.clone(), msg_parsed).await;
+                        | ClientMessageType::GetRepositories
+                        | ClientMessageType::GetRepository { .. }
+                        | ClientMessageType::GetPollingHistory { .. } => {
+                            repository_handler(ctx.clone(), id, msg_parsed).await;
                         }
-                        _ => {
-                            continue;
+                        ClientMessageType::SetJenkinsMapping { .. }
+                        | ClientMessageType::DeleteJenkinsMapping { .. }
+                        | ClientMessageType::GetJenkinsMappings { .. } => {
+                            jenkins_handler(ctx.clone(), id, msg_parsed).await;
+                        }
+                        ClientMessageType::TriggerBuild { .. }
+                        | ClientMessageType::GetBuildHistory { .. } => {
+                            build_handler(ctx.clone(), id, msg_parsed).await;
+                        }
+                        ClientMessageType::AddBranchWatchRule { .. }
+                        | ClientMessageType::DeleteBranchWatchRule { .. }
+                        | ClientMessageType::GetBranches { .. }
+                        | ClientMessageType::GetBranchBuildHistory { .. }
+                        | ClientMessageType::GetBranchWatchRules { .. } => {
+                            branch_handler(ctx.clone(), id, msg_parsed).await;
+                        }
+                        ClientMessageType::AddTagRule { .. }
+                        | ClientMessageType::DeleteTagRule { .. }
+                        | ClientMessageType::GetTags { .. }
+                        | ClientMessageType::GetTagBuildHistory { .. }
+                        | ClientMessageType::GetTagRules { .. } => {
+                            tag_handler(ctx.clone(), id, msg_parsed).await;
+                        }
+                        ClientMessageType::GetPullRequests { .. }
+                        | ClientMessageType::GetPullRequestHistory { .. } => {
+                            pull_request_handler(ctx.clone(), id, msg_parsed).await;
+                        }
+                        ClientMessageType::Subscribe { .. }
+                        | ClientMessageType::Unsubscribe { .. }
+                        | ClientMessageType::GetSubscriptions => {
+                            subscription_handler(ctx.clone(), id, msg_parsed).await;
+                        }
+                        ClientMessageType::UpdateSystemSetting { .. }
+                        | ClientMessageType::GetSystemSettings => {
+                            system_setting_handler(ctx.clone(), id, msg_parsed).await;
+                        }
+                        ClientMessageType::GetAuditLog { .. } => {
+                            audit_handler(ctx.clone(), id, msg_parsed).await;
+                        }
+                        ClientMessageType::ReplayWebhookDelivery { .. }
+                        | ClientMessageType::GetWebhookDeliveries { .. } => {
+                            webhook_handler(ctx.clone(), id, msg_parsed).await;
                         }
                     }
+
+                    if let Some(audit) = audit {
+                        audit.finish(&ctx, id).await;
+                    }
                 }
+
+                // 연결 종료 시 클라이언트 목록에서 제거
+                clients.lock().await.remove(&id);
             });
         }
     }
 
+    /// 업그레이드 요청의 Bearer 토큰으로 연결 인증
+    ///
+    /// 유효하지 않은 토큰이면 `Unauthorized` 에러를 전송하고 false 반환
+    async fn authenticate_connection(ctx: &HandlerContext, id: Uuid, token: &str) -> bool {
+        let reason = match auth::authenticate(&ctx.pool, token).await {
+            Ok(Some(identity)) => {
+                info!(
+                    "Client {} authenticated: {} ({})",
+                    id,
+                    identity.name,
+                    identity.role.as_str()
+                );
+                if let Some(client) = ctx.clients.lock().await.get_mut(&id) {
+                    client.identity = Some(identity);
+                }
+                return true;
+            }
+            Ok(None) => "Invalid API token".to_string(),
+            Err(e) => {
+                error!("Failed to authenticate: {}", e);
+                "Failed to authenticate".to_string()
+            }
+        };
+
+        warn!("Connection rejected: {}, {}", id, reason);
+        WsServer::send_error_message(
+            ctx.clients.clone(),
+            id,
+            None,
+            ErrorCode::Unauthorized,
+            &reason,
+            None,
+        )
+        .await;
+        false
+    }
+
+    /// 연결의 인증 주체 (미인증이면 None)
+    pub async fn identity(
+        clients: &Arc<Mutex<HashMap<Uuid, Client>>>,
+        id: Uuid,
+    ) -> Option<auth::Identity> {
+        clients
+            .lock()
+            .await
+            .get(&id)
+            .and_then(|client| client.identity.clone())
+    }
+
+    /// 연결의 역할이 `required` 이상인지 확인
+    pub async fn authorize(
+        clients: &Arc<Mutex<HashMap<Uuid, Client>>>,
+        id: Uuid,
+        required: Role,
+    ) -> Result<(), (ErrorCode, String)> {
+        let guard = clients.lock().await;
+        match guard.get(&id).and_then(|client| client.identity.as_ref()) {
+            None => Err((
+                ErrorCode::Unauthorized,
+                "Authentication required".to_string(),
+            )),
+            Some(identity) if !identity.has_role(required) => Err((
+                ErrorCode::Forbidden,
+                format!("{} role required", required.as_str()),
+            )),
+            Some(_) => Ok(()),
+        }
+    }
+
+    /// 연결 종료 및 클라이언트 목록에서 제거
+    async fn close_connection(clients: Arc<Mutex<HashMap<Uuid, Client>>>, id: Uuid) {
+        let Some(client) = clients.lock().await.remove(&id) else {
+            return;
+        };
+        if let Err(e) = client.write.lock().await.close().await {
+            error!("Failed to close connection: {}, {}", id, e);
+        }
+    }
+
     pub async fn send_message(clients: Arc<Mutex<HashMap<Uuid, Client>>>, id: Uuid, message: &str) {
         let write = {
             let guard = clients.lock().await;
@@ -157,6 +429,76 @@ impl WsServer {
         }
     }
 
+    /// 이벤트 버스의 이벤트를 구독 중인 클라이언트에 전송
+    ///
+    /// 저장소와 무관한 이벤트는 인증된 모든 클라이언트에 전송
+    async fn forward_events(
+        clients: Arc<Mutex<HashMap<Uuid, Client>>>,
+        mut events: broadcast::Receiver<ServerMessageType>,
+    ) {
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!("Event bus lagged, {} events skipped", skipped);
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+
+            let repo_id = event.repo_id();
+            let server_message = ServerMessage {
+                id: None,
+                payload: event,
+            };
+            WsServer::broadcast_message(
+                clients.clone(),
+                repo_id,
+                serde_json::to_string(&server_message).unwrap().as_str(),
+            )
+            .await;
+        }
+    }
+
+    /// 인증된 클라이언트에 메시지 전송
+    ///
+    /// `repo_id`가 있으면 해당 저장소를 구독 중인 클라이언트에만 전송하며,
+    /// 전송에 실패한 클라이언트는 끊어진 연결로 보고 목록에서 제거
+    pub async fn broadcast_message(
+        clients: Arc<Mutex<HashMap<Uuid, Client>>>,
+        repo_id: Option<i32>,
+        message: &str,
+    ) {
+        let writers: Vec<(Uuid, Arc<Mutex<WebSocketWriter>>)> = {
+            let guard = clients.lock().await;
+            guard
+                .iter()
+                .filter(|(_, client)| {
+                    client.identity.is_some()
+                        && repo_id.is_none_or(|repo_id| client.subscriptions.matches(repo_id))
+                })
+                .map(|(id, client)| (*id, client.write.clone()))
+                .collect()
+        }; // clients 락 해제
+
+        let mut dead = Vec::new();
+        for (id, write) in writers {
+            let mut wl = write.lock().await;
+            if let Err(e) = wl.send(Message::text(message)).await {
+                error!("broadcast message error: {}, {}", id, e);
+                dead.push(id);
+            }
+        }
+
+        if !dead.is_empty() {
+            let mut guard = clients.lock().await;
+            for id in dead {
+                guard.remove(&id);
+                info!("Client pruned: {}", id);
+            }
+        }
+    }
+
     pub async fn send_error_message(
         clients: Arc<Mutex<HashMap<Uuid, Client>>>,
         id: Uuid,
@@ -168,9 +510,9 @@ impl WsServer {
         let server_message = ServerMessage {
             id: msg_id,
             payload: ServerMessageType::Error {
-                code: code,
+                code,
                 message: message.to_string(),
-                details: details,
+                details,
             },
         };
 